@@ -0,0 +1,58 @@
+/// Generates a module that includes every solution in `src/bin`, so the main binary can run them in-process.
+/// Only with the `in-process` feature, otherwise a day that does not compile would break every command.
+/// See `src/template/registry.rs`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut solutions: Vec<(String, String)> = vec![];
+
+    // with `dhat-heap`, every solution declares a global allocator, so they can not share a binary.
+    let is_in_process = env::var("CARGO_FEATURE_IN_PROCESS").is_ok();
+    if is_in_process && env::var("CARGO_FEATURE_DHAT_HEAP").is_err() {
+        let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+        for entry in fs::read_dir(bin_dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_stem().and_then(|x| x.to_str()) else {
+                continue;
+            };
+
+            let is_solution = path.extension().is_some_and(|x| x == "rs")
+                && name.len() == 7
+                && name.as_bytes()[4] == b'-'
+                && name.chars().filter(|c| c.is_ascii_digit()).count() == 6;
+
+            if is_solution {
+                let module = format!("solution_{}", name.replace('-', "_"));
+                solutions.push((module, path.to_string_lossy().into_owned()));
+            }
+        }
+    }
+
+    solutions.sort();
+
+    let mut code = String::new();
+
+    for (module, path) in &solutions {
+        code.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code, unused)]\n#[path = {path:?}]\nmod {module};\n\n"
+        ));
+    }
+
+    code.push_str("/// Registry entries of all solutions that are compiled into this binary.\n");
+    code.push_str("pub fn all() -> Vec<advent_of_code::template::registry::Solution> {\n");
+    code.push_str("    #[allow(unused_mut)]\n    let mut solutions = vec![];\n");
+    for (module, _) in &solutions {
+        code.push_str(&format!(
+            "    #[cfg(not(test))]\n    solutions.push({module}::SOLUTION);\n"
+        ));
+    }
+    code.push_str("    solutions\n}\n");
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), code).unwrap();
+}
@@ -16,23 +16,12 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 fn count_xmas(crossword: &Board, coord: &Coord) -> u32 {
-    let directions = [
-      Vector { x: -1, y: -1 },  
-      Vector { x: -1, y: 0 },  
-      Vector { x: -1, y: 1 },  
-      Vector { x: 0, y: -1 },  
-      Vector { x: 0, y: 1 },  
-      Vector { x: 1, y: -1 },  
-      Vector { x: 1, y: 0 },  
-      Vector { x: 1, y: 1 },  
-    ];
-    
-    let result = directions
-        .map(|dir| check_direction(crossword, coord, &dir))
-        .map(|has_xmas| if has_xmas { 1 } else { 0 })
-        .iter().sum();
+    let result = crossword
+        .neighbours8(coord)
+        .filter(|(neighbour, _)| check_direction(crossword, coord, &neighbour.subtract(coord)))
+        .count();
 
-    return result;
+    return result as u32;
 }
 
 fn check_direction(crossword: &Board, coord: &Coord, direction: &Vector) -> bool {
@@ -78,10 +67,10 @@ fn has_cross_mas(crossword: &Board, coord: &Coord) -> bool {
         return false;
     }
     
-    let top_left = coord.add(&Vector { x: -1, y: -1 });
-    let top_right = coord.add(&Vector { x: -1, y: 1 });
-    let bottom_left = coord.add(&Vector { x: 1, y: -1 });
-    let bottom_right = coord.add(&Vector { x: 1, y: 1 });
+    let top_left = coord.add(&Vector::NORTH_WEST);
+    let top_right = coord.add(&Vector::NORTH_EAST);
+    let bottom_left = coord.add(&Vector::SOUTH_WEST);
+    let bottom_right = coord.add(&Vector::SOUTH_EAST);
     
     if is_m_and_s(crossword.at(&top_left), crossword.at(&bottom_right)) 
         && is_m_and_s(crossword.at(&top_right), crossword.at(&bottom_left)) {
@@ -108,13 +97,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(9));
     }
 }
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+use advent_of_code::{Board, Coord, Searchable, Vector};
+use advent_of_code::cycle::{simulate_until_cycle, Detection, Simulation};
+use advent_of_code::parallel::par_count;
+
+advent_of_code::solution!(6);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let board = Board::from(input);
+    let initial_coord = board.find('^').unwrap();
+    let visited = visited_coords(&board, &initial_coord);
+    
+    return Some(visited.len() as u32);
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+struct DirectedCoord {
+    coord: Coord,
+    direction: Vector,
+}
+
+/// Returns `None` once the guard leaves the board
+fn make_step(board: &Board, guard: &DirectedCoord) -> Option<DirectedCoord> {
+    let next_coord = guard.coord + guard.direction;
+    
+    return match board.at(&next_coord)? {
+        '.' | '^' => Some(DirectedCoord { coord: next_coord, direction: guard.direction }),
+        '#' => Some(DirectedCoord { coord: guard.coord, direction: guard.direction.rotate_right() }),
+        x => panic!("Invalid character: {}", x),
+    };
+}
+
+fn simulate(board: &Board, coord: &Coord, direction: &Vector, detection: Detection) -> Simulation<DirectedCoord> {
+    let initial = DirectedCoord { coord: *coord, direction: *direction };
+    return simulate_until_cycle(initial, |guard| make_step(board, guard), detection);
+}
+
+fn visited_coords(board: &Board, coord: &Coord) -> HashSet<Coord> {
+    let simulation = simulate(board, coord, &Vector::NORTH, Detection::Hashing);
+    return simulation.visited.iter().map(|guard| guard.coord).collect();
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let empty_board = Board::from(input);
+    let initial_coord = empty_board.find('^').unwrap();
+    let candidates: Vec<Coord> = visited_coords(&empty_board, &initial_coord)
+        .into_iter()
+        .filter(|coord| coord != &initial_coord)
+        .collect();
+
+    let result = par_count(&candidates, |coord| {
+        let mut mutated_board = empty_board.clone();
+        mutated_board.mutate(coord, '#');
+        return has_loop(&mutated_board, &initial_coord, &Vector::NORTH);
+    });
+    
+    return Some(result as u32);
+}
+
+fn has_loop(board: &Board, coord: &Coord, direction: &Vector) -> bool {
+    return simulate(board, coord, direction, Detection::Brent).has_cycle();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(41));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(6));
+    }
+    
+    #[test]
+    fn test_has_loop_1_1() {
+        let board = Board::new(vec![vec!['^']]);
+        let actual = has_loop(&board, &Coord::new(0, 0), &Vector::NORTH);
+        assert_eq!(actual, false);
+    }
+
+    #[test]
+    fn test_has_loop_3_3() {
+        let board = Board::new(vec![
+            vec!['.','#','.'],
+            vec!['#','^','#'],
+            vec!['.','#','.'],
+        ]);
+        let actual = has_loop(&board, &Coord::new(1, 1), &Vector::NORTH);
+        assert_eq!(actual, true);
+    }
+}
@@ -1,19 +1,16 @@
 use itertools::{Itertools, MultiProduct};
 use std::slice::Iter;
 use advent_of_code::num_digits;
+use advent_of_code::parallel::par_sum;
 
 advent_of_code::solution!(7);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (results, operands_list) = extract_results_and_operands(input);
+    let equations = extract_equations(input);
 
-    let mut answer = 0;
-    
-    for (i, operands) in operands_list.iter().enumerate() {
-        if is_solvable_2_op(results[i], operands) {
-            answer += results[i];
-        }
-    }
+    let answer = par_sum(&equations, |(result, operands)| {
+        return if is_solvable_2_op(*result, operands) { *result } else { 0 };
+    });
 
     return Some(answer);
 }
@@ -91,15 +88,11 @@ fn calculate_2_op(operands: &Vec<u32>, op_order: u32) -> u64 {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (results, operands_list) = extract_results_and_operands(input);
+    let equations = extract_equations(input);
 
-    let mut answer = 0;
-
-    for (i, operands) in operands_list.iter().enumerate() {
-        if is_solvable_3_op(results[i], operands) {
-            answer += results[i];
-        }
-    }
+    let answer = par_sum(&equations, |(result, operands)| {
+        return if is_solvable_3_op(*result, operands) { *result } else { 0 };
+    });
 
     return Some(answer);
 }
@@ -124,6 +117,11 @@ fn extract_results_and_operands(input: &str) -> (Vec<u64>, Vec<Vec<u32>>) {
     (results, operands_list)
 }
 
+fn extract_equations(input: &str) -> Vec<(u64, Vec<u32>)> {
+    let (results, operands_list) = extract_results_and_operands(input);
+    return results.into_iter().zip(operands_list).collect();
+}
+
 fn is_solvable_3_op(result: u64, operands: &Vec<u32>) -> bool {
     let operators_len = operands.len() - 1;
     let cartesian_product = all_operator_permutations(operators_len);
@@ -175,13 +173,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11387));
     }
     
@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use advent_of_code::{Board, Bounded, Coord, Vector};
+use advent_of_code::{Board, Bounded, Coord};
 
 advent_of_code::solution!(8);
 
@@ -29,8 +29,8 @@ impl AntennaMap {
     
     fn insert(&mut self, c: char, coord: &Coord) {
         match self.0.get_mut(&c) {
-            Some(vec) => vec.push(coord.clone()),
-            None => { self.0.insert(c, vec![coord.clone()]); },
+            Some(vec) => vec.push(*coord),
+            None => { self.0.insert(c, vec![*coord]); },
         }
     }
     
@@ -41,7 +41,7 @@ impl AntennaMap {
             for coord in coords {
                 for other_coord in coords {
                     if coord != other_coord {
-                        result.push((coord.clone(), other_coord.clone()));
+                        result.push((*coord, *other_coord));
                     }
                 }
             }
@@ -58,50 +58,29 @@ pub fn part_one(input: &str) -> Option<u32> {
 
     for pair in antennas.pairs() {
         let pair_antinodes = get_antinodes(&board, &pair);
-        pair_antinodes.iter().for_each(|antinode| { antinodes.insert(antinode.clone()); });
+        pair_antinodes.iter().for_each(|antinode| { antinodes.insert(*antinode); });
     }
 
     return Some(antinodes.len() as u32);
 }
 
 fn get_antinodes(board: &Board, pair: &(Coord, Coord)) -> Vec<Coord> {
-    let vector1 = Vector::new(pair.0.x - pair.1.x, pair.0.y - pair.1.y);
-    let vector2 = Vector::new(pair.1.x - pair.0.x, pair.1.y - pair.0.y);
-    let mut result = Vec::new();
-    
-    let antinode1 = pair.0.add(&vector1);
-    let antinode2 = pair.1.add(&vector2);
-    
-    if board.is_in_bounds(&antinode1) {
-        result.push(antinode1);
-    }
-    
-    if board.is_in_bounds(&antinode2) {
-        result.push(antinode2);
-    }
-    
-    return result;
+    let antinode1 = pair.0 + (pair.0 - pair.1);
+    let antinode2 = pair.1 + (pair.1 - pair.0);
+
+    return [antinode1, antinode2]
+        .into_iter()
+        .filter(|antinode| board.is_in_bounds(antinode))
+        .collect();
 }
 
 fn get_resonant_antinodes(board: &Board, pair: &(Coord, Coord)) -> Vec<Coord> {
-    let vector1 = Vector::new(pair.0.x - pair.1.x, pair.0.y - pair.1.y);
-    let vector2 = Vector::new(pair.1.x - pair.0.x, pair.1.y - pair.0.y);
-    let mut result = Vec::new();
-    
-    let mut antinode = pair.0.clone();
-    
-    while board.is_in_bounds(&antinode) {
-        result.push(antinode.clone());
-        antinode = antinode.add(&vector1);
-    }
-
-    let mut antinode = pair.1.clone();
-
-    while board.is_in_bounds(&antinode) {
-        result.push(antinode.clone());
-        antinode = antinode.add(&vector2);
-    }
+    let direction = (pair.0 - pair.1).normalized();
+    let forward = pair.0.steps(direction);
+    let backward = pair.0.steps(-direction).skip(1);
 
+    let mut result: Vec<Coord> = forward.take_while(|antinode| board.is_in_bounds(antinode)).collect();
+    result.extend(backward.take_while(|antinode| board.is_in_bounds(antinode)));
     return result;
 }
 
@@ -112,7 +91,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     for pair in antennas.pairs() {
         let resonant_antinodes = get_resonant_antinodes(&board, &pair);
-        resonant_antinodes.iter().for_each(|antinode| { antinodes.insert(antinode.clone()); });
+        resonant_antinodes.iter().for_each(|antinode| { antinodes.insert(*antinode); });
     }
 
     return Some(antinodes.len() as u32);
@@ -124,7 +103,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(14));
     }
     
@@ -138,7 +117,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(34));
     }
 }
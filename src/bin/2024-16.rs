@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+use advent_of_code::{Board, Coord, Direction, Searchable};
+use advent_of_code::graph::NodeId;
+use advent_of_code::maze::{Maze, Move, StateExpansion};
+
+advent_of_code::solution!(16);
+
+const STEP_WEIGHT: u32 = 1;
+const TURN_WEIGHT: u32 = 1000;
+
+fn cost(step: &Move) -> Option<u32> {
+    return match step {
+        Move::Step { .. } => Some(STEP_WEIGHT),
+        Move::Turn { .. } => Some(TURN_WEIGHT),
+    };
+}
+
+fn convert_board_to_graph(board: &Board) -> Maze<u32> {
+    return Maze::from_board(board, |tag| tag != '#', StateExpansion::PositionAndFacing, cost);
+}
+
+fn find_start_node_id(maze: &Maze<u32>, board: &Board) -> NodeId {
+    let start_coord = board.find('S').unwrap();
+    return maze.node_id(&start_coord, Some(Direction::East)).unwrap();
+}
+
+fn score(board: &Board) -> u32 {
+    let maze = convert_board_to_graph(board);
+    let start_node_id = find_start_node_id(&maze, board);
+    let end_node_ids = maze.find_nodes_by_tag('E');
+    let paths = maze.graph.shortest_paths(start_node_id);
+    let final_node_id = paths.nearest(&end_node_ids).unwrap();
+    return paths.distance(final_node_id).unwrap();
+}
+
+/// Counts tiles that lie on at least one of the best paths from start to end
+fn count_best_path_tiles(board: &Board) -> u32 {
+    let maze = convert_board_to_graph(board);
+    let start_node_id = find_start_node_id(&maze, board);
+    let end_node_ids = maze.find_nodes_by_tag('E');
+    let paths = maze.graph.all_shortest_paths(start_node_id);
+    let best_distance = paths.distance(paths.nearest(&end_node_ids).unwrap());
+    let best_end_node_ids: Vec<NodeId> = end_node_ids
+        .into_iter()
+        .filter(|&node_id| paths.distance(node_id) == best_distance)
+        .collect();
+    let node_ids: Vec<NodeId> = paths.nodes_on_paths_to(&best_end_node_ids).into_iter().collect();
+    let tiles: HashSet<Coord> = maze.coords(&node_ids).into_iter().collect();
+    return tiles.len() as u32;
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let board = Board::from(input);
+    return Some(score(&board));
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let board = Board::from(input);
+    return Some(count_best_path_tiles(&board));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_east() {
+        let input = [
+          "####",  
+          "#SE#",  
+          "####",  
+        ].join("\n");
+        let board = Board::from(&input);
+        let actual = score(&board);
+        assert_eq!(actual, 1);
+    }
+
+    #[test]
+    fn test_score_north() {
+        let input = [
+            "###",
+            "#E#",
+            "#S#",
+            "###",
+        ].join("\n");
+        let board = Board::from(&input);
+        let actual = score(&board);
+        assert_eq!(actual, 1001);
+    }
+
+    #[test]
+    fn test_score_turn_around() {
+        let input = [
+            "####",
+            "#ES#",
+            "####",
+        ].join("\n");
+        let board = Board::from(&input);
+        let actual = score(&board);
+        assert_eq!(actual, 2001);
+    }
+
+    #[test]
+    fn test_count_best_path_tiles_split() {
+        let input = [
+            "#####",
+            "#...#",
+            "#S#E#",
+            "#...#",
+            "#####",
+        ].join("\n");
+        let board = Board::from(&input);
+        let actual = count_best_path_tiles(&board);
+        assert_eq!(actual, 8);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(7036));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(45));
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Strategy used by `simulate_until_cycle` to recognise a repeated state
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Detection {
+    /// Remembers every state, keeps the visited sequence
+    Hashing,
+    /// Floyd's tortoise and hare, constant memory
+    Floyd,
+    /// Brent's power-of-two search, constant memory and fewer steps than Floyd
+    Brent,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Outcome {
+    /// Step function returned `None` after `len` states, counting the initial one
+    Terminated { len: usize },
+    /// State with index `start` repeats every `length` steps
+    Cycle { start: usize, length: usize },
+}
+
+#[derive(Clone, Debug)]
+pub struct Simulation<S> {
+    pub outcome: Outcome,
+    /// States in visiting order, each state at most once.
+    /// Only recorded with `Detection::Hashing`, empty otherwise.
+    pub visited: Vec<S>,
+}
+
+impl<S> Simulation<S> {
+    pub fn has_cycle(&self) -> bool {
+        return matches!(self.outcome, Outcome::Cycle { .. });
+    }
+}
+
+/// Applies `step` starting from `initial` until a state repeats or `step` returns `None`.
+/// `step` has to be deterministic, constant-memory modes evaluate it several times per state.
+pub fn simulate_until_cycle<S: Hash + Eq + Clone>(
+    initial: S,
+    step: impl FnMut(&S) -> Option<S>,
+    detection: Detection,
+) -> Simulation<S> {
+    return match detection {
+        Detection::Hashing => hashing(initial, step),
+        Detection::Floyd => Simulation { outcome: floyd(initial, step), visited: Vec::new() },
+        Detection::Brent => Simulation { outcome: brent(initial, step), visited: Vec::new() },
+    };
+}
+
+fn hashing<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Simulation<S> {
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut visited = Vec::new();
+    let mut maybe_state = Some(initial);
+
+    while let Some(state) = maybe_state {
+        if let Some(&start) = indices.get(&state) {
+            let outcome = Outcome::Cycle { start, length: visited.len() - start };
+            return Simulation { outcome, visited };
+        }
+
+        maybe_state = step(&state);
+        indices.insert(state.clone(), visited.len());
+        visited.push(state);
+    }
+
+    return Simulation { outcome: Outcome::Terminated { len: visited.len() }, visited };
+}
+
+fn floyd<S: Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Outcome {
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    let mut hare_index = 0;
+
+    loop {
+        for _ in 0..2 {
+            match step(&hare) {
+                Some(next) => hare = next,
+                None => return Outcome::Terminated { len: hare_index + 1 },
+            }
+            hare_index += 1;
+        }
+
+        // tortoise follows the states hare has already been to, so it never terminates
+        tortoise = step(&tortoise).unwrap();
+
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise).unwrap();
+        hare = step(&hare).unwrap();
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise).unwrap();
+    while tortoise != hare {
+        hare = step(&hare).unwrap();
+        length += 1;
+    }
+
+    return Outcome::Cycle { start, length };
+}
+
+fn brent<S: Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Outcome {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let Some(mut hare) = step(&initial) else {
+        return Outcome::Terminated { len: 1 };
+    };
+    let mut hare_index = 1;
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        match step(&hare) {
+            Some(next) => hare = next,
+            None => return Outcome::Terminated { len: hare_index + 1 },
+        }
+        hare_index += 1;
+        length += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare).unwrap();
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise).unwrap();
+        hare = step(&hare).unwrap();
+        start += 1;
+    }
+
+    return Outcome::Cycle { start, length };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_DETECTIONS: [Detection; 3] = [Detection::Hashing, Detection::Floyd, Detection::Brent];
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    fn rho(state: &u32) -> Option<u32> {
+        return Some(if *state == 4 { 2 } else { state + 1 });
+    }
+
+    fn countdown(state: &u32) -> Option<u32> {
+        return state.checked_sub(1);
+    }
+
+    #[test]
+    fn test_cycle() {
+        for detection in ALL_DETECTIONS {
+            let simulation = simulate_until_cycle(0, rho, detection);
+            assert_eq!(simulation.outcome, Outcome::Cycle { start: 2, length: 3 }, "{:?}", detection);
+            assert!(simulation.has_cycle());
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        for detection in ALL_DETECTIONS {
+            let simulation = simulate_until_cycle(7, |&state| Some(state), detection);
+            assert_eq!(simulation.outcome, Outcome::Cycle { start: 0, length: 1 }, "{:?}", detection);
+        }
+    }
+
+    #[test]
+    fn test_terminated() {
+        for detection in ALL_DETECTIONS {
+            for initial in 0..5 {
+                let simulation = simulate_until_cycle(initial, countdown, detection);
+                let expected = Outcome::Terminated { len: initial as usize + 1 };
+                assert_eq!(simulation.outcome, expected, "{:?} from {}", detection, initial);
+                assert!(!simulation.has_cycle());
+            }
+        }
+    }
+
+    #[test]
+    fn test_visited() {
+        let simulation = simulate_until_cycle(0, rho, Detection::Hashing);
+        assert_eq!(simulation.visited, vec![0, 1, 2, 3, 4]);
+        assert_eq!(simulate_until_cycle(0, rho, Detection::Brent).visited, Vec::<u32>::new());
+        assert_eq!(simulate_until_cycle(2, countdown, Detection::Hashing).visited, vec![2, 1, 0]);
+    }
+}
@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt::Display;
+use crate::Vector;
+
+/// One of the 8 compass points, assuming north points to the top row of a board
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All compass points clockwise, starting from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Cardinal compass points clockwise, starting from north
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Turns clockwise by `n_eighths` eighths of a full circle, counterclockwise if negative
+    pub fn turn(&self, n_eighths: i32) -> Direction {
+        let index = (self.index() as i32 + n_eighths).rem_euclid(8);
+        return Direction::ALL[index as usize];
+    }
+
+    /// Turns clockwise by 90 degrees
+    pub fn rotate_right(&self) -> Direction {
+        return self.turn(2);
+    }
+
+    /// Turns counterclockwise by 90 degrees
+    pub fn rotate_left(&self) -> Direction {
+        return self.turn(-2);
+    }
+
+    pub fn reverse(&self) -> Direction {
+        return self.turn(4);
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        return self.index() % 2 == 1;
+    }
+
+    pub fn to_vector(&self) -> Vector {
+        return Vector::DIRECTIONS_8[self.index()];
+    }
+
+    /// `None` unless `vector` is one of the 8 unit steps
+    pub fn from_vector(vector: &Vector) -> Option<Direction> {
+        return Vector::DIRECTIONS_8
+            .iter()
+            .position(|direction| direction == vector)
+            .map(|index| Direction::ALL[index]);
+    }
+
+    fn index(&self) -> usize {
+        return *self as usize;
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        return direction.to_vector();
+    }
+}
+
+impl TryFrom<&Vector> for Direction {
+    type Error = DirectionFromVectorError;
+
+    fn try_from(vector: &Vector) -> Result<Self, Self::Error> {
+        return Direction::from_vector(vector).ok_or(DirectionFromVectorError);
+    }
+}
+
+/// Accepts arrows `^>v<`, compass letters `NESW` and `UDLR`
+impl TryFrom<char> for Direction {
+    type Error = DirectionFromCharError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        return match c {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            _ => Err(DirectionFromCharError(c)),
+        };
+    }
+}
+
+/// An error which can be returned when converting a [`Vector`] into a [`Direction`].
+#[derive(Debug)]
+pub struct DirectionFromVectorError;
+
+impl Error for DirectionFromVectorError {}
+
+impl Display for DirectionFromVectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str("expecting a vector of a single step in one of 8 directions");
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`] from a character.
+#[derive(Debug)]
+pub struct DirectionFromCharError(pub char);
+
+impl Error for DirectionFromCharError {}
+
+impl Display for DirectionFromCharError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "expecting one of `^>v<`, `NESW` or `UDLR`, got `{}`", self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction::North.rotate_right(), Direction::East);
+        assert_eq!(Direction::West.rotate_right(), Direction::North);
+        assert_eq!(Direction::North.rotate_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.rotate_left(), Direction::NorthWest);
+        assert_eq!(Direction::South.reverse(), Direction::North);
+        assert_eq!(Direction::SouthWest.reverse(), Direction::NorthEast);
+    }
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Direction::North.turn(1), Direction::NorthEast);
+        assert_eq!(Direction::North.turn(-1), Direction::NorthWest);
+        assert_eq!(Direction::East.turn(8), Direction::East);
+        assert_eq!(Direction::East.turn(-13), Direction::SouthWest);
+    }
+
+    #[test]
+    fn test_vector_conversion() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_vector(&direction.to_vector()), Some(direction));
+        }
+        assert_eq!(Vector::from(Direction::South), Vector::SOUTH);
+        assert_eq!(Direction::from_vector(&Vector::new(2, 0)), None);
+        assert!(Direction::try_from(&Vector::new(0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_is_diagonal() {
+        assert!(Direction::SouthEast.is_diagonal());
+        assert!(!Direction::West.is_diagonal());
+    }
+
+    #[test]
+    fn test_from_char() {
+        for (chars, expected) in ["^NU", ">ER", "vSD", "<WL"].iter().zip(Direction::CARDINALS) {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c).unwrap(), expected);
+            }
+        }
+        assert!(Direction::try_from('x').is_err());
+    }
+}
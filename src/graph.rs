@@ -0,0 +1,435 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::ops::Add;
+
+pub type NodeId = usize;
+
+/// Edge weight that can be summed up along a path, starting from `ZERO`
+pub trait Weight: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_weight!(u8, u16, u32, u64, usize, i32, i64);
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Edge<W> {
+    pub to: NodeId,
+    pub weight: W,
+}
+
+/// Directed graph stored as adjacency lists.
+/// Node with id i has always index i in `nodes`.
+#[derive(Clone, Debug)]
+pub struct WeightedGraph<N, W> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<Edge<W>>>,
+}
+
+impl<N, W: Weight> WeightedGraph<N, W> {
+    pub fn new() -> WeightedGraph<N, W> {
+        return WeightedGraph { nodes: Vec::new(), edges: Vec::new() };
+    }
+
+    pub fn with_capacity(capacity: usize) -> WeightedGraph<N, W> {
+        return WeightedGraph {
+            nodes: Vec::with_capacity(capacity),
+            edges: Vec::with_capacity(capacity),
+        };
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        return id;
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.edges[from].push(Edge { to, weight });
+    }
+
+    /// Adds edges in both directions with the same weight
+    pub fn add_undirected_edge(&mut self, node1: NodeId, node2: NodeId, weight: W) {
+        self.add_edge(node1, node2, weight);
+        self.add_edge(node2, node1, weight);
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        return &self.nodes[id];
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        return self.nodes.iter().enumerate();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    pub fn edges(&self, id: NodeId) -> &[Edge<W>] {
+        return &self.edges[id];
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        return self.edges[id].iter().map(|edge| edge.to);
+    }
+
+    pub fn find_node(&self, predicate: impl Fn(&N) -> bool) -> Option<NodeId> {
+        return self.nodes.iter().position(predicate);
+    }
+
+    pub fn find_nodes(&self, predicate: impl Fn(&N) -> bool) -> Vec<NodeId> {
+        return self.nodes()
+            .filter(|(_, node)| predicate(node))
+            .map(|(id, _)| id)
+            .collect();
+    }
+
+    /// Dijkstra's algorithm over a binary heap, visits every node reachable from `source`.
+    /// Keeps a single parent per node, so only one optimal path can be traced back.
+    pub fn shortest_paths(&self, source: NodeId) -> ShortestPaths<W> {
+        return self.dijkstra(source, false);
+    }
+
+    /// Same as `shortest_paths`, but records every equal-cost predecessor,
+    /// so that all optimal paths can be enumerated.
+    /// Zero-weight edges can make the predecessors cyclic, `all_paths_to` only yields paths without repeated nodes.
+    pub fn all_shortest_paths(&self, source: NodeId) -> ShortestPaths<W> {
+        return self.dijkstra(source, true);
+    }
+
+    fn dijkstra(&self, source: NodeId, record_all_parents: bool) -> ShortestPaths<W> {
+        let mut distances: Vec<Option<W>> = vec![None; self.len()];
+        let mut parents: Vec<Vec<NodeId>> = vec![Vec::new(); self.len()];
+        let mut heap = BinaryHeap::new();
+
+        distances[source] = Some(W::ZERO);
+        heap.push(Reverse((W::ZERO, source)));
+
+        while let Some(Reverse((distance, node_id))) = heap.pop() {
+            if distances[node_id].is_some_and(|known| known < distance) {
+                continue;
+            }
+
+            for edge in &self.edges[node_id] {
+                let alt = distance + edge.weight;
+
+                if distances[edge.to].is_none_or(|known| alt < known) {
+                    distances[edge.to] = Some(alt);
+                    parents[edge.to].clear();
+                    parents[edge.to].push(node_id);
+                    heap.push(Reverse((alt, edge.to)));
+                } else if record_all_parents
+                    && distances[edge.to] == Some(alt)
+                    && edge.to != source
+                    && !parents[edge.to].contains(&node_id)
+                {
+                    parents[edge.to].push(node_id);
+                }
+            }
+        }
+
+        return ShortestPaths { source, distances, parents };
+    }
+}
+
+impl<N, W: Weight> Default for WeightedGraph<N, W> {
+    fn default() -> Self {
+        return WeightedGraph::new();
+    }
+}
+
+/// Result of a single-source shortest path search
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<W> {
+    source: NodeId,
+    distances: Vec<Option<W>>,
+    /// Predecessors on optimal paths, holds at most one entry unless all parents were recorded
+    parents: Vec<Vec<NodeId>>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    pub fn source(&self) -> NodeId {
+        return self.source;
+    }
+
+    /// `None` if `node` is unreachable from the source
+    pub fn distance(&self, node: NodeId) -> Option<W> {
+        return self.distances[node];
+    }
+
+    pub fn distances(&self) -> &[Option<W>] {
+        return &self.distances;
+    }
+
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        return self.parents[node].first().copied();
+    }
+
+    pub fn predecessors(&self, node: NodeId) -> &[NodeId] {
+        return &self.parents[node];
+    }
+
+    pub fn is_reachable(&self, node: NodeId) -> bool {
+        return self.distances[node].is_some();
+    }
+
+    /// Closest of `targets`, ties are resolved in favour of the earlier target
+    pub fn nearest(&self, targets: &[NodeId]) -> Option<NodeId> {
+        return targets
+            .iter()
+            .filter_map(|&target| Some((self.distances[target]?, target)))
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, target)| target);
+    }
+
+    /// Node ids from the source to `target` inclusive, `None` if `target` is unreachable
+    pub fn path_to(&self, target: NodeId) -> Option<Vec<NodeId>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut result = vec![target];
+        let mut maybe_previous = self.parent(target);
+
+        while let Some(previous) = maybe_previous {
+            result.push(previous);
+            maybe_previous = self.parent(previous);
+        }
+
+        result.reverse();
+        return Some(result);
+    }
+
+    /// Every optimal path from the source to `target`, empty if `target` is unreachable
+    pub fn all_paths_to(&self, target: NodeId) -> AllPaths<'_, W> {
+        let stack = if self.is_reachable(target) { vec![vec![target]] } else { Vec::new() };
+        return AllPaths { shortest_paths: self, stack };
+    }
+
+    /// Nodes that lie on at least one optimal path from the source to any of `targets`
+    pub fn nodes_on_paths_to(&self, targets: &[NodeId]) -> HashSet<NodeId> {
+        let mut result = HashSet::new();
+        let mut stack: Vec<NodeId> = targets
+            .iter()
+            .copied()
+            .filter(|&target| self.is_reachable(target))
+            .collect();
+
+        while let Some(node) = stack.pop() {
+            if result.insert(node) {
+                stack.extend_from_slice(&self.parents[node]);
+            }
+        }
+
+        return result;
+    }
+}
+
+/// Iterator over every optimal path to a target, yields node ids from the source to the target
+pub struct AllPaths<'a, W> {
+    shortest_paths: &'a ShortestPaths<W>,
+    /// Partial paths traced back from the target, i.e. in reverse order
+    stack: Vec<Vec<NodeId>>,
+}
+
+impl<W: Weight> Iterator for AllPaths<'_, W> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut path) = self.stack.pop() {
+            let last = *path.last().unwrap();
+
+            if last == self.shortest_paths.source {
+                path.reverse();
+                return Some(path);
+            }
+
+            // predecessors over zero-weight edges can lead back onto the path
+            for &previous in self.shortest_paths.predecessors(last) {
+                if path.contains(&previous) {
+                    continue;
+                }
+
+                let mut next_path = path.clone();
+                next_path.push(previous);
+                self.stack.push(next_path);
+            }
+        }
+
+        return None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -1-> c
+    ///  \-----5-----/
+    fn triangle() -> WeightedGraph<char, u32> {
+        let mut graph = WeightedGraph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, c, 1);
+        graph.add_edge(a, c, 5);
+        return graph;
+    }
+
+    #[test]
+    fn test_add_node_assigns_sequential_ids() {
+        let graph = triangle();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.node(1), &'b');
+        assert_eq!(graph.find_node(|&tag| tag == 'c'), Some(2));
+        assert_eq!(graph.find_nodes(|&tag| tag != 'b'), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let graph = triangle();
+        assert_eq!(graph.neighbours(0).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(graph.neighbours(2).count(), 0);
+    }
+
+    #[test]
+    fn test_shortest_paths_prefers_cheaper_detour() {
+        let graph = triangle();
+        let paths = graph.shortest_paths(0);
+        assert_eq!(paths.distance(0), Some(0));
+        assert_eq!(paths.distance(1), Some(1));
+        assert_eq!(paths.distance(2), Some(2));
+        assert_eq!(paths.path_to(2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_shortest_paths_directed() {
+        let graph = triangle();
+        let paths = graph.shortest_paths(2);
+        assert_eq!(paths.distance(0), None);
+        assert_eq!(paths.path_to(0), None);
+        assert_eq!(paths.path_to(2), Some(vec![2]));
+    }
+
+    #[test]
+    fn test_shortest_paths_undirected() {
+        let mut graph: WeightedGraph<(), u64> = WeightedGraph::new();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_undirected_edge(a, b, 7);
+        assert_eq!(graph.shortest_paths(b).distance(a), Some(7));
+    }
+
+    #[test]
+    fn test_nearest() {
+        let mut graph = triangle();
+        let d = graph.add_node('d');
+        let paths = graph.shortest_paths(0);
+        assert_eq!(paths.nearest(&[2, 1]), Some(1));
+        assert_eq!(paths.nearest(&[d]), None);
+        assert_eq!(paths.nearest(&[]), None);
+    }
+
+    /// a -1-> b -1-> d
+    ///  \-1-> c -1-/
+    /// plus a dead end a -5-> e
+    fn diamond() -> WeightedGraph<char, u32> {
+        let mut graph = WeightedGraph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        let d = graph.add_node('d');
+        let e = graph.add_node('e');
+        graph.add_edge(a, b, 1);
+        graph.add_edge(a, c, 1);
+        graph.add_edge(b, d, 1);
+        graph.add_edge(c, d, 1);
+        graph.add_edge(a, e, 5);
+        return graph;
+    }
+
+    #[test]
+    fn test_shortest_paths_keeps_single_parent() {
+        let paths = diamond().shortest_paths(0);
+        assert_eq!(paths.predecessors(3).len(), 1);
+        assert_eq!(paths.all_paths_to(3).count(), 1);
+    }
+
+    #[test]
+    fn test_all_shortest_paths_records_every_predecessor() {
+        let paths = diamond().all_shortest_paths(0);
+        assert_eq!(paths.distance(3), Some(2));
+        assert_eq!(paths.predecessors(3), &[1, 2]);
+        assert_eq!(paths.predecessors(0), &[] as &[NodeId]);
+    }
+
+    #[test]
+    fn test_all_paths_to() {
+        let paths = diamond().all_shortest_paths(0);
+        let mut actual: Vec<Vec<NodeId>> = paths.all_paths_to(3).collect();
+        actual.sort();
+        assert_eq!(actual, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+        assert_eq!(paths.all_paths_to(0).collect::<Vec<_>>(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_all_shortest_paths_with_zero_weight_cycle() {
+        let mut graph = WeightedGraph::new();
+        let s = graph.add_node('s');
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        graph.add_edge(s, a, 1);
+        graph.add_edge(s, b, 1);
+        graph.add_edge(a, b, 0);
+        graph.add_edge(b, a, 0);
+
+        let paths = graph.all_shortest_paths(s);
+        assert_eq!(paths.predecessors(a), &[s, b]);
+        assert_eq!(paths.predecessors(b), &[s, a]);
+
+        let mut actual: Vec<Vec<NodeId>> = paths.all_paths_to(b).collect();
+        actual.sort();
+        assert_eq!(actual, vec![vec![s, a, b], vec![s, b]]);
+        assert_eq!(paths.nodes_on_paths_to(&[b]), HashSet::from([s, a, b]));
+    }
+
+    #[test]
+    fn test_all_shortest_paths_with_parallel_edges() {
+        let mut graph = WeightedGraph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        graph.add_edge(a, b, 2);
+        graph.add_edge(a, b, 2);
+
+        let paths = graph.all_shortest_paths(a);
+        assert_eq!(paths.predecessors(b), &[a]);
+        assert_eq!(paths.all_paths_to(b).count(), 1);
+    }
+
+    #[test]
+    fn test_all_paths_to_unreachable() {
+        let paths = diamond().all_shortest_paths(3);
+        assert_eq!(paths.all_paths_to(0).count(), 0);
+    }
+
+    #[test]
+    fn test_nodes_on_paths_to() {
+        let paths = diamond().all_shortest_paths(0);
+        assert_eq!(paths.nodes_on_paths_to(&[3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(paths.nodes_on_paths_to(&[1, 4]), HashSet::from([0, 1, 4]));
+    }
+}
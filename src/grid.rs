@@ -0,0 +1,377 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::{Coord, Vector};
+
+pub trait Bounded {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn is_in_bounds(&self, coord: &Coord) -> bool;
+}
+
+pub trait Searchable<T> {
+    fn find(&self, value: T) -> Option<Coord>;
+
+    fn count(&self, value: T) -> u32;
+}
+
+/// Rectangular grid stored row by row in one contiguous vector.
+/// Assuming zero coordinate of the grid is at top left corner,
+/// `x` is the row index and `y` is the column index.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+pub type Board = Grid<char>;
+
+impl<T> Grid<T> {
+    /// Panics if the rows have different lengths
+    pub fn new(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+
+        for row in rows {
+            assert_eq!(row.len(), width, "All rows of a grid must have the same length");
+            cells.extend(row);
+        }
+
+        return Grid { width, height, cells };
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+
+    pub fn get(&self, coord: &Coord) -> Option<&T> {
+        if self.is_in_bounds(coord) {
+            return Some(&self.cells[self.index_of(coord)]);
+        }
+
+        return None;
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        if self.is_in_bounds(coord) {
+            let index = self.index_of(coord);
+            return Some(&mut self.cells[index]);
+        }
+
+        return None;
+    }
+
+    pub fn at(&self, coord: &Coord) -> Option<T> where T: Copy {
+        return self.get(coord).copied();
+    }
+
+    /// Panics if `coord` is out of bounds
+    pub fn mutate(&mut self, coord: &Coord, value: T) {
+        self[coord] = value;
+    }
+
+    pub fn row(&self, x: usize) -> &[T] {
+        return &self.cells[x * self.width..(x + 1) * self.width];
+    }
+
+    /// Yields `height` rows, which are all empty if the grid has a width of zero
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|x| self.row(x));
+    }
+
+    pub fn column(&self, y: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(y).step_by(self.width.max(1)).take(self.height);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|y| self.column(y));
+    }
+
+    /// All coordinates in row-major order
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        return (0..self.width * self.height)
+            .map(move |index| Coord::new((index / width) as i32, (index % width) as i32));
+    }
+
+    /// All cells along with their coordinates in row-major order
+    pub fn cells(&self) -> impl Iterator<Item = (Coord, &T)> {
+        return self.coords().zip(self.cells.iter());
+    }
+
+    /// In-bounds orthogonal neighbours of `coord` clockwise, starting from north
+    pub fn neighbours4(&self, coord: &Coord) -> impl Iterator<Item = (Coord, T)> + '_ where T: Copy {
+        return self.neighbours(coord, &Vector::DIRECTIONS_4);
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `coord` clockwise, starting from north
+    pub fn neighbours8(&self, coord: &Coord) -> impl Iterator<Item = (Coord, T)> + '_ where T: Copy {
+        return self.neighbours(coord, &Vector::DIRECTIONS_8);
+    }
+
+    /// Same as `neighbours4`, but skips cells holding any of `walls`
+    pub fn neighbours4_except<'a>(&'a self, coord: &Coord, walls: &'a [T]) -> impl Iterator<Item = (Coord, T)> + 'a
+    where
+        T: Copy + PartialEq,
+    {
+        return self.neighbours4(coord).filter(move |(_, value)| !walls.contains(value));
+    }
+
+    /// Same as `neighbours8`, but skips cells holding any of `walls`
+    pub fn neighbours8_except<'a>(&'a self, coord: &Coord, walls: &'a [T]) -> impl Iterator<Item = (Coord, T)> + 'a
+    where
+        T: Copy + PartialEq,
+    {
+        return self.neighbours8(coord).filter(move |(_, value)| !walls.contains(value));
+    }
+
+    fn neighbours<'a>(&'a self, coord: &Coord, directions: &'a [Vector]) -> impl Iterator<Item = (Coord, T)> + 'a
+    where
+        T: Copy,
+    {
+        let coord = *coord;
+        return directions.iter().filter_map(move |direction| {
+            let neighbour = coord.add(direction);
+            let value = self.at(&neighbour)?;
+            return Some((neighbour, value));
+        });
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        };
+    }
+
+    fn index_of(&self, coord: &Coord) -> usize {
+        return coord.x as usize * self.width + coord.y as usize;
+    }
+}
+
+impl Board {
+    /// Trailing blank lines are skipped.
+    /// Panics if the remaining lines have different lengths
+    pub fn from(input: &str) -> Board {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+
+        return Board::new(lines.iter().map(|line| line.chars().collect()).collect());
+    }
+}
+
+impl<T> Bounded for Grid<T> {
+    fn width(&self) -> usize {
+        return self.width;
+    }
+
+    fn height(&self) -> usize {
+        return self.height;
+    }
+
+    fn is_in_bounds(&self, coord: &Coord) -> bool {
+        return coord.x >= 0
+            && coord.y >= 0
+            && coord.x < (self.height as i32)
+            && coord.y < (self.width as i32);
+    }
+}
+
+impl<T: PartialEq> Searchable<T> for Grid<T> {
+    fn find(&self, value: T) -> Option<Coord> {
+        return self.cells()
+            .find(|(_, cell)| **cell == value)
+            .map(|(coord, _)| coord);
+    }
+
+    fn count(&self, value: T) -> u32 {
+        return self.cells.iter().filter(|&cell| *cell == value).count() as u32;
+    }
+}
+
+impl<T> Index<&Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: &Coord) -> &T {
+        assert!(self.is_in_bounds(coord), "Coordinate {:?} is out of bounds", coord);
+        return &self.cells[self.index_of(coord)];
+    }
+}
+
+impl<T> IndexMut<&Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: &Coord) -> &mut T {
+        assert!(self.is_in_bounds(coord), "Coordinate {:?} is out of bounds", coord);
+        let index = self.index_of(coord);
+        return &mut self.cells[index];
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        return &self[&coord];
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        return &mut self[&coord];
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Board {
+        return Board::from("ab.\n.#c");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let board = board();
+        assert_eq!(board.width(), 3);
+        assert_eq!(board.height(), 2);
+        assert_eq!(board.at(&Coord::new(1, 1)), Some('#'));
+        assert_eq!(board.at(&Coord::new(2, 0)), None);
+        assert_eq!(board.at(&Coord::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let board = Board::from("");
+        assert_eq!(board.width(), 0);
+        assert_eq!(board.height(), 0);
+        assert_eq!(board.rows().count(), 0);
+        assert_eq!(board.to_string(), "");
+    }
+
+    #[test]
+    fn test_from_str_trailing_blank_lines() {
+        assert_eq!(Board::from("ab.\n.#c\n\n"), board());
+        assert_eq!(Board::from("ab.\r\n.#c\r\n  \n\t\n"), board());
+        assert_eq!(Board::from(" \n\n").height(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_str_ragged_rows() {
+        Board::from("ab.\n.#\n");
+    }
+
+    #[test]
+    fn test_zero_width_rows() {
+        let grid: Grid<u8> = Grid::new(vec![vec![], vec![]]);
+        assert_eq!(grid.width(), 0);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.rows().count(), 2);
+        assert!(grid.rows().all(|row| row.is_empty()));
+        assert_eq!(grid.to_string(), "\n\n");
+        assert_eq!(Grid::filled(0, 3, 'x').rows().count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_ragged_rows() {
+        Grid::new(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_index_and_mutate() {
+        let mut grid = Grid::filled(2, 3, 0u32);
+        grid[Coord::new(2, 1)] = 5;
+        grid.mutate(&Coord::new(0, 0), 1);
+        assert_eq!(grid[&Coord::new(2, 1)], 5);
+        assert_eq!(grid.get(&Coord::new(0, 0)), Some(&1));
+        assert_eq!(grid.get(&Coord::new(3, 0)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::filled(2, 2, 0u32);
+        let _ = grid[Coord::new(0, 2)];
+    }
+
+    #[test]
+    fn test_find_and_count() {
+        let board = board();
+        assert_eq!(board.find('c'), Some(Coord::new(1, 2)));
+        assert_eq!(board.find('z'), None);
+        assert_eq!(board.count('.'), 2);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let board = board();
+        assert_eq!(board.row(1), &['.', '#', 'c']);
+        assert_eq!(board.rows().count(), 2);
+        assert_eq!(board.column(2).collect::<String>(), ".c");
+        assert_eq!(
+            board.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(),
+            vec!["a.", "b#", ".c"],
+        );
+    }
+
+    #[test]
+    fn test_cells() {
+        let board = board();
+        let cells: Vec<(Coord, char)> = board.cells().map(|(coord, &c)| (coord, c)).collect();
+        assert_eq!(cells[0], (Coord::new(0, 0), 'a'));
+        assert_eq!(cells[4], (Coord::new(1, 1), '#'));
+        assert_eq!(cells.len(), 6);
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let board = board();
+        let actual: Vec<(Coord, char)> = board.neighbours4(&Coord::new(0, 1)).collect();
+        assert_eq!(actual, vec![
+            (Coord::new(0, 2), '.'),
+            (Coord::new(1, 1), '#'),
+            (Coord::new(0, 0), 'a'),
+        ]);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let board = board();
+        assert_eq!(board.neighbours8(&Coord::new(0, 1)).count(), 5);
+        assert_eq!(board.neighbours8(&Coord::new(1, 2)).map(|(_, c)| c).collect::<String>(), ".#b");
+        assert_eq!(board.neighbours8(&Coord::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours_except() {
+        let board = board();
+        let actual: Vec<char> = board.neighbours4_except(&Coord::new(0, 1), &['#']).map(|(_, c)| c).collect();
+        assert_eq!(actual, vec!['.', 'a']);
+        let actual: Vec<char> = board.neighbours8_except(&Coord::new(0, 1), &['#', '.']).map(|(_, c)| c).collect();
+        assert_eq!(actual, vec!['c', 'a']);
+    }
+
+    #[test]
+    fn test_map_and_display() {
+        let grid = board().map(|&c| if c == '#' { 1 } else { 0 });
+        assert_eq!(grid.to_string(), "000\n010\n");
+        assert_eq!(board().to_string(), "ab.\n.#c\n");
+    }
+}
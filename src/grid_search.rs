@@ -0,0 +1,227 @@
+use std::collections::{HashSet, VecDeque};
+use crate::{Bounded, Coord, Grid, Vector};
+
+/// Connected area of orthogonally adjacent cells holding the same value
+#[derive(Clone, Debug)]
+pub struct Region<T> {
+    pub value: T,
+    pub cells: HashSet<Coord>,
+}
+
+/// Result of connected-component labelling, `labels` holds the index into `regions` for every cell
+#[derive(Clone, Debug)]
+pub struct Regions<T> {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region<T>>,
+}
+
+impl<T> Grid<T> {
+    /// Breadth-first search distances in steps from `source`, `None` for unreachable cells.
+    /// Moves go to orthogonal neighbours for which `is_passable` holds.
+    pub fn distances_from(&self, source: &Coord, is_passable: impl Fn(&T) -> bool) -> Grid<Option<u32>> {
+        return self.distances_from_many(&[*source], is_passable);
+    }
+
+    /// Same as `distances_from`, but every cell gets its distance to the closest of `sources`
+    pub fn distances_from_many(&self, sources: &[Coord], is_passable: impl Fn(&T) -> bool) -> Grid<Option<u32>> {
+        let mut distances: Grid<Option<u32>> = Grid::filled(self.width(), self.height(), None);
+        let mut queue = VecDeque::new();
+
+        for source in sources.iter().filter(|source| self.is_in_bounds(source)) {
+            distances[source] = Some(0);
+            queue.push_back(*source);
+        }
+
+        while let Some(coord) = queue.pop_front() {
+            let distance = distances[coord].unwrap();
+
+            for neighbour in self.passable_neighbours(&coord, &is_passable) {
+                if distances[neighbour].is_none() {
+                    distances[neighbour] = Some(distance + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        return distances;
+    }
+
+    /// Cells reachable from `start` through orthogonal neighbours holding the same value as `start`.
+    /// Empty if `start` is out of bounds.
+    pub fn flood_fill(&self, start: &Coord) -> HashSet<Coord> where T: PartialEq {
+        let Some(value) = self.get(start) else {
+            return HashSet::new();
+        };
+
+        return self.fill(start, |cell| cell == value);
+    }
+
+    /// Splits the grid into regions of orthogonally adjacent cells with equal values
+    pub fn regions(&self) -> Regions<T> where T: PartialEq + Clone {
+        let mut labels: Grid<Option<usize>> = Grid::filled(self.width(), self.height(), None);
+        let mut regions: Vec<Region<T>> = Vec::new();
+
+        for (coord, value) in self.cells() {
+            if labels[coord].is_some() {
+                continue;
+            }
+
+            let cells = self.fill(&coord, |cell| cell == value);
+            for cell in &cells {
+                labels[cell] = Some(regions.len());
+            }
+            regions.push(Region { value: value.clone(), cells });
+        }
+
+        return Regions { labels: labels.map(|label| label.unwrap()), regions };
+    }
+
+    fn fill(&self, start: &Coord, is_member: impl Fn(&T) -> bool) -> HashSet<Coord> {
+        let mut result = HashSet::from([*start]);
+        let mut stack = vec![*start];
+
+        while let Some(coord) = stack.pop() {
+            for neighbour in self.passable_neighbours(&coord, &is_member) {
+                if result.insert(neighbour) {
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        return result;
+    }
+
+    fn passable_neighbours<'a>(&'a self, coord: &Coord, is_passable: &'a impl Fn(&T) -> bool) -> impl Iterator<Item = Coord> + 'a {
+        let coord = *coord;
+        return Vector::DIRECTIONS_4
+            .iter()
+            .map(move |&direction| coord + direction)
+            .filter(move |neighbour| self.get(neighbour).is_some_and(is_passable));
+    }
+}
+
+impl<T> Region<T> {
+    pub fn area(&self) -> u32 {
+        return self.cells.len() as u32;
+    }
+
+    pub fn contains(&self, coord: &Coord) -> bool {
+        return self.cells.contains(coord);
+    }
+
+    /// Number of cell edges bordering on other regions or on the outside of the grid
+    pub fn perimeter(&self) -> u32 {
+        let mut result = 0;
+
+        for cell in &self.cells {
+            for direction in Vector::DIRECTIONS_4 {
+                if !self.contains(&(*cell + direction)) {
+                    result += 1;
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /// Number of straight fence segments around the region, including around holes.
+    /// Counted as the number of corners, since every side ends in exactly one corner.
+    pub fn sides(&self) -> u32 {
+        let mut result = 0;
+
+        for cell in &self.cells {
+            for direction in Vector::DIRECTIONS_4 {
+                let next_direction = direction.rotate_right();
+                let has_side = self.contains(&(*cell + direction));
+                let has_next_side = self.contains(&(*cell + next_direction));
+                let has_diagonal = self.contains(&(*cell + direction + next_direction));
+
+                let is_convex_corner = !has_side && !has_next_side;
+                let is_concave_corner = has_side && has_next_side && !has_diagonal;
+
+                if is_convex_corner || is_concave_corner {
+                    result += 1;
+                }
+            }
+        }
+
+        return result;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Board;
+    use super::*;
+
+    fn garden() -> Board {
+        return Board::from("AAAA\nBBCD\nBBCC\nEEEC");
+    }
+
+    fn find_region(regions: &Regions<char>, value: char) -> &Region<char> {
+        return regions.regions.iter().find(|region| region.value == value).unwrap();
+    }
+
+    #[test]
+    fn test_distances_from() {
+        let board = Board::from("..#\n.##\n...");
+        let distances = board.distances_from(&Coord::new(0, 0), |&c| c != '#');
+        assert_eq!(distances[Coord::new(0, 1)], Some(1));
+        assert_eq!(distances[Coord::new(2, 2)], Some(4));
+        assert_eq!(distances[Coord::new(0, 2)], None);
+        assert_eq!(distances[Coord::new(1, 1)], None);
+    }
+
+    #[test]
+    fn test_distances_from_many() {
+        let board = Board::from(".....");
+        let distances = board.distances_from_many(&[Coord::new(0, 0), Coord::new(0, 4)], |_| true);
+        let actual: Vec<Option<u32>> = distances.row(0).to_vec();
+        assert_eq!(actual, vec![Some(0), Some(1), Some(2), Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let board = garden();
+        let actual = board.flood_fill(&Coord::new(1, 2));
+        let expected = HashSet::from([
+            Coord::new(1, 2),
+            Coord::new(2, 2),
+            Coord::new(2, 3),
+            Coord::new(3, 3),
+        ]);
+        assert_eq!(actual, expected);
+        assert_eq!(board.flood_fill(&Coord::new(9, 9)).len(), 0);
+    }
+
+    #[test]
+    fn test_regions() {
+        let regions = garden().regions();
+        assert_eq!(regions.regions.len(), 5);
+        assert_eq!(regions.labels[Coord::new(0, 0)], regions.labels[Coord::new(0, 3)]);
+        assert_ne!(regions.labels[Coord::new(0, 0)], regions.labels[Coord::new(1, 0)]);
+
+        let expected = [('A', 4, 10, 4), ('B', 4, 8, 4), ('C', 4, 10, 8), ('D', 1, 4, 4), ('E', 3, 8, 4)];
+        for (value, area, perimeter, sides) in expected {
+            let region = find_region(&regions, value);
+            assert_eq!((region.area(), region.perimeter(), region.sides()), (area, perimeter, sides), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_regions_with_holes() {
+        let board = Board::from("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        let regions = board.regions();
+        assert_eq!(regions.regions.len(), 5);
+        let outer = find_region(&regions, 'O');
+        assert_eq!(outer.area(), 21);
+        assert_eq!(outer.perimeter(), 36);
+        assert_eq!(outer.sides(), 20);
+    }
+
+    #[test]
+    fn test_regions_not_connected_diagonally() {
+        let board = Board::from("AB\nBA");
+        assert_eq!(board.regions().regions.len(), 4);
+    }
+}
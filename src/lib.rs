@@ -1,127 +1,89 @@
+use std::iter::Successors;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
 pub mod template;
+pub mod cycle;
+pub mod direction;
+pub mod graph;
+pub mod grid;
+pub mod grid_search;
+pub mod maze;
+pub mod parallel;
+
+pub use direction::Direction;
+pub use grid::{Board, Bounded, Grid, Searchable};
 
-#[derive(PartialEq, Clone, Eq, Hash, Debug)]
+#[derive(PartialEq, Clone, Copy, Eq, Hash, Debug)]
 pub struct Coord {
     pub x: i32,
     pub y: i32,
 }
 
-#[derive(PartialEq, Clone, Eq, Hash, Debug)]
+#[derive(PartialEq, Clone, Copy, Eq, Hash, Debug)]
 pub struct Vector {
     pub x: i32,
     pub y: i32,
 }
 
-/// Assuming zero coordinate of the board is at top left corner
-#[derive(PartialEq, Clone)]
-pub struct Board {
-    pub cells: Vec<Vec<char>>,
-}
-
-pub trait Bounded {
-    fn width(&self) -> usize;
-    fn height(&self) -> usize;
-    fn is_in_bounds(&self, coord: &Coord) -> bool;
-}
-
-pub trait Searchable {
-    fn find(&self, char: char) -> Option<Coord>;
-    
-    fn count(&self, char: char) -> u32;
-}
+impl Coord {
+    pub fn new(x: i32, y: i32) -> Coord {
+        return Coord { x, y };
+    }
 
-impl Board {
-    pub fn new(cells: Vec<Vec<char>>) -> Board {
-        return Board { cells };
+    pub fn add(&self, vector: &Vector) -> Coord {
+        return Coord { x: self.x + vector.x, y: self.y + vector.y };
     }
     
-    pub fn from(input: &str) -> Board {
-        return Board::new(input.lines().map(|line| line.chars().collect()).collect());
+    pub fn subtract(&self, other: &Coord) -> Vector {
+        return Vector { x: self.x - other.x, y: self.y - other.y };
     }
 
-    pub fn at(&self, coord: &Coord) -> Option<char> {
-        if self.is_in_bounds(coord) {
-            return Some(self.cells[coord.x as usize][coord.y as usize]);
-        };
-
-        return None;
+    pub fn manhattan_distance(&self, other: &Coord) -> u32 {
+        return (*self - *other).manhattan_length();
     }
 
-    pub fn mutate(&mut self, coord: &Coord, value: char) {
-        self.cells[coord.x as usize][coord.y as usize] = value;
+    pub fn chebyshev_distance(&self, other: &Coord) -> u32 {
+        return (*self - *other).chebyshev_length();
     }
-}
 
-impl Bounded for Board {
-    fn width(&self) -> usize {
-        if self.cells.len() == 0 {
-            return 0;
-        }
-        
-        return self.cells[0].len();
+    /// Infinite iterator over `self`, `self + step`, `self + 2 * step` and so on
+    pub fn steps(&self, step: Vector) -> Successors<Coord, impl FnMut(&Coord) -> Option<Coord>> {
+        return std::iter::successors(Some(*self), move |&coord| Some(coord + step));
     }
+}
 
-    fn height(&self) -> usize {
-        return self.cells.len();
-    }
-    
-    fn is_in_bounds(&self, coord: &Coord) -> bool {
-        return coord.x >= 0 
-            && coord.y >= 0
-            && coord.x < (self.height() as i32) 
-            && coord.y < (self.width() as i32);
-    }
-}
-
-impl Searchable for Board {
-    fn find(&self, char: char) -> Option<Coord> {
-        for i in 0..self.height() {
-            for j in 0..self.width() {
-                if self.cells[i][j] == char {
-                    return Some(Coord::new(i as i32, j as i32));
-                }
-            }
-        }
+impl Add<Vector> for Coord {
+    type Output = Coord;
 
-        return None;
+    fn add(self, vector: Vector) -> Coord {
+        return Coord { x: self.x + vector.x, y: self.y + vector.y };
     }
+}
 
-    fn count(&self, char: char) -> u32 {
-        let mut result = 0;
-        for i in 0..self.height() {
-            for j in 0..self.width() {
-                if self.cells[i][j] == char {
-                    result += 1;
-                }
-            }
-        }
-        return result;
+impl AddAssign<Vector> for Coord {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
     }
 }
 
-impl std::fmt::Display for Board {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.cells {
-            for cell in row {
-                write!(f, "{}", cell)?;
-            }
-            writeln!(f)?;
-        }
+impl Sub<Vector> for Coord {
+    type Output = Coord;
 
-        return Ok(());
+    fn sub(self, vector: Vector) -> Coord {
+        return self + -vector;
     }
 }
 
-impl Coord {
-    pub fn new(x: i32, y: i32) -> Coord {
-        return Coord { x, y };
+impl SubAssign<Vector> for Coord {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
     }
+}
 
-    pub fn add(&self, vector: &Vector) -> Coord {
-        return Coord { x: self.x + vector.x, y: self.y + vector.y };
-    }
-    
-    pub fn subtract(&self, other: &Coord) -> Vector {
+impl Sub for Coord {
+    type Output = Vector;
+
+    fn sub(self, other: Coord) -> Vector {
         return Vector { x: self.x - other.x, y: self.y - other.y };
     }
 }
@@ -131,19 +93,105 @@ impl Vector {
     pub const SOUTH: Vector = Vector { x: 1, y: 0 };
     pub const WEST: Vector = Vector { x: 0, y: -1 };
     pub const EAST: Vector = Vector { x: 0, y: 1 };
+    pub const NORTH_EAST: Vector = Vector { x: -1, y: 1 };
+    pub const SOUTH_EAST: Vector = Vector { x: 1, y: 1 };
+    pub const SOUTH_WEST: Vector = Vector { x: 1, y: -1 };
+    pub const NORTH_WEST: Vector = Vector { x: -1, y: -1 };
+
+    /// Cardinal directions clockwise, starting from north
+    pub const DIRECTIONS_4: [Vector; 4] = [Vector::NORTH, Vector::EAST, Vector::SOUTH, Vector::WEST];
+
+    /// Cardinal and diagonal directions clockwise, starting from north
+    pub const DIRECTIONS_8: [Vector; 8] = [
+        Vector::NORTH,
+        Vector::NORTH_EAST,
+        Vector::EAST,
+        Vector::SOUTH_EAST,
+        Vector::SOUTH,
+        Vector::SOUTH_WEST,
+        Vector::WEST,
+        Vector::NORTH_WEST,
+    ];
     
     pub fn new(x: i32, y: i32) -> Vector {
         return Vector { x, y };
     }
 
+    /// Rotates clockwise by 90 degrees, e.g. north becomes east
     pub fn rotate_right(&self) -> Vector {
-        match self {
-            &Vector::NORTH => Vector::EAST,
-            &Vector::EAST => Vector::SOUTH,
-            &Vector::SOUTH => Vector::WEST,
-            &Vector::WEST => Vector::NORTH,
-            _ => panic!("Rotating unsupported vector"),
+        return Vector { x: self.y, y: -self.x };
+    }
+
+    /// Rotates counterclockwise by 90 degrees, e.g. north becomes west
+    pub fn rotate_left(&self) -> Vector {
+        return Vector { x: -self.y, y: self.x };
+    }
+
+    pub fn reverse(&self) -> Vector {
+        return -*self;
+    }
+
+    pub fn manhattan_length(&self) -> u32 {
+        return self.x.unsigned_abs() + self.y.unsigned_abs();
+    }
+
+    pub fn chebyshev_length(&self) -> u32 {
+        return self.x.unsigned_abs().max(self.y.unsigned_abs());
+    }
+
+    /// Smallest vector with integer components pointing the same way,
+    /// e.g. `(4, -6)` becomes `(2, -3)`. Zero vector stays as is.
+    pub fn normalized(&self) -> Vector {
+        let divisor = gcd(self.x, self.y);
+        if divisor == 0 {
+            return *self;
         }
+        return *self / divisor;
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        return Vector { x: self.x + other.x, y: self.y + other.y };
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        return Vector { x: self.x - other.x, y: self.y - other.y };
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i32) -> Vector {
+        return Vector { x: self.x * factor, y: self.y * factor };
+    }
+}
+
+/// Panics if any component is not divisible by `divisor`
+impl Div<i32> for Vector {
+    type Output = Vector;
+
+    fn div(self, divisor: i32) -> Vector {
+        assert!(
+            self.x % divisor == 0 && self.y % divisor == 0,
+            "{:?} is not divisible by {}", self, divisor,
+        );
+        return Vector { x: self.x / divisor, y: self.y / divisor };
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        return Vector { x: -self.x, y: -self.y };
     }
 }
 
@@ -156,3 +204,84 @@ pub fn num_digits(x: u64) -> u32 {
     }
     return i;
 }
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+pub fn gcd(a: i32, b: i32) -> i32 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_rotate() {
+        assert_eq!(Vector::NORTH.rotate_right(), Vector::EAST);
+        assert_eq!(Vector::WEST.rotate_right(), Vector::NORTH);
+        assert_eq!(Vector::NORTH.rotate_left(), Vector::WEST);
+        assert_eq!(Vector::NORTH_EAST.rotate_right(), Vector::SOUTH_EAST);
+        assert_eq!(Vector::new(2, 3).rotate_right().rotate_left(), Vector::new(2, 3));
+        assert_eq!(Vector::SOUTH_WEST.reverse(), Vector::NORTH_EAST);
+    }
+
+    #[test]
+    fn test_vector_operators() {
+        assert_eq!(Vector::NORTH + Vector::EAST, Vector::NORTH_EAST);
+        assert_eq!(Vector::NORTH - Vector::EAST, Vector::NORTH_WEST);
+        assert_eq!(Vector::SOUTH * 3, Vector::new(3, 0));
+        assert_eq!(-Vector::new(1, -2), Vector::new(-1, 2));
+    }
+
+    #[test]
+    fn test_vector_div_and_normalized() {
+        assert_eq!(Vector::new(4, -6) / 2, Vector::new(2, -3));
+        assert_eq!(Vector::new(4, -6).normalized(), Vector::new(2, -3));
+        assert_eq!(Vector::new(0, 5).normalized(), Vector::EAST);
+        assert_eq!(Vector::new(0, 0).normalized(), Vector::new(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_div_not_divisible() {
+        let _ = Vector::new(3, 4) / 2;
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_coord_operators() {
+        let mut coord = Coord::new(1, 1) + Vector::SOUTH_EAST;
+        assert_eq!(coord, Coord::new(2, 2));
+        assert_eq!(coord - Coord::new(5, 0), Vector::new(-3, 2));
+        assert_eq!(coord - Vector::EAST, Coord::new(2, 1));
+        coord += Vector::NORTH * 2;
+        assert_eq!(coord, Coord::new(0, 2));
+        coord -= Vector::WEST;
+        assert_eq!(coord, Coord::new(0, 3));
+    }
+
+    #[test]
+    fn test_distances() {
+        let coord = Coord::new(1, -2);
+        assert_eq!(coord.manhattan_distance(&Coord::new(4, 2)), 7);
+        assert_eq!(coord.chebyshev_distance(&Coord::new(4, 2)), 4);
+        assert_eq!(coord.manhattan_distance(&coord), 0);
+    }
+
+    #[test]
+    fn test_steps() {
+        let actual: Vec<Coord> = Coord::new(0, 0).steps(Vector::new(1, 2)).take(3).collect();
+        assert_eq!(actual, vec![Coord::new(0, 0), Coord::new(1, 2), Coord::new(2, 4)]);
+    }
+}
@@ -1,4 +1,5 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
+use advent_of_code::template::registry;
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,12 +8,16 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{Day, DaySelection, Scheduling, Year};
+    use std::{process, time::Duration};
+
+    /// Percentage by which a median has to change to count as regression or improvement.
+    const DEFAULT_COMPARE_THRESHOLD: f64 = 10.0;
 
     pub enum AppArguments {
         Download {
             day: Day,
+            force: bool,
         },
         Read {
             day: Day,
@@ -21,44 +26,91 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            force: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
+            parallel: bool,
             submit: Option<u8>,
         },
         All {
+            selection: DaySelection,
             release: bool,
+            parallel: bool,
+            scheduling: Scheduling,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            selection: DaySelection,
             store: bool,
+            parallel: bool,
+            compare_threshold: Option<f64>,
+            scheduling: Scheduling,
+        },
+        TimeHistory {
+            day: Day,
+        },
+        Verify {
+            selection: DaySelection,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the `--year` it applies to, which defaults to `AOC_YEAR`.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        let Some(year) = args.opt_value_from_str("--year")?.or_else(Year::from_env) else {
+            eprintln!("No year specified. Pass `--year <year>` or set AOC_YEAR.");
+            process::exit(1);
+        };
+
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                let release = args.contains("--release");
+                let parallel = args.contains("--parallel");
+                let scheduling = parse_scheduling(&mut args)?;
+
+                AppArguments::All {
+                    selection: parse_selection(&mut args)?,
+                    release,
+                    parallel,
+                    scheduling,
+                }
+            }
             Some("time") => {
+                if let Some(day) = args.opt_value_from_str("--history")? {
+                    return finish(args, year, AppArguments::TimeHistory { day });
+                }
+
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let parallel = args.contains("--parallel");
+                let compare = args.contains("--compare");
+                // benchmarks run one day at a time unless asked otherwise, so timings do not interfere.
+                let scheduling = parse_scheduling(&mut args)?;
+                let threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(DEFAULT_COMPARE_THRESHOLD);
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    selection: parse_selection(&mut args)?,
                     store,
+                    parallel,
+                    compare_threshold: compare.then_some(threshold),
+                    scheduling,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                selection: parse_selection(&mut args)?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
+                force: args.contains("--force"),
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
@@ -67,12 +119,14 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                force: args.contains("--force"),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                parallel: args.contains("--parallel"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -86,49 +140,114 @@ mod args {
             }
         };
 
+        finish(args, year, app_args)
+    }
+
+    /// Parses `--jobs <n>`, the number of days to run at the same time, and `--timeout <secs>` per day.
+    fn parse_scheduling(args: &mut pico_args::Arguments) -> Result<Scheduling, pico_args::Error> {
+        let defaults = Scheduling::default();
+
+        Ok(Scheduling {
+            jobs: args
+                .opt_value_from_str::<_, usize>("--jobs")?
+                .map_or(defaults.jobs, |jobs| jobs.max(1)),
+            timeout: args
+                .opt_value_from_str::<_, u64>("--timeout")?
+                .map(Duration::from_secs)
+                .or(defaults.timeout),
+        })
+    }
+
+    /// Parses the days a multi-day command runs, e.g. `1-5,8,16 --since 10 --unsolved --changed`.
+    /// The list of days is a free argument, so this has to be called after all other options are parsed.
+    fn parse_selection(args: &mut pico_args::Arguments) -> Result<DaySelection, pico_args::Error> {
+        Ok(DaySelection {
+            since: args.opt_value_from_str("--since")?,
+            unsolved: args.contains("--unsolved"),
+            changed: args.contains("--changed"),
+            list: args.opt_free_from_str()?,
+        })
+    }
+
+    fn finish(
+        args: pico_args::Arguments,
+        year: Year,
+        app_args: AppArguments,
+    ) -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
     }
 }
 
+/// The solutions in `src/bin`, generated by `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 fn main() {
+    registry::register(solutions::all());
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                selection,
+                release,
+                parallel,
+                scheduling,
+            } => all::handle(year, &selection, release, parallel, scheduling),
+            AppArguments::Time {
+                selection,
+                all,
+                store,
+                parallel,
+                compare_threshold,
+                scheduling,
+            } => time::handle(
+                year,
+                &selection,
+                all,
+                store,
+                parallel,
+                compare_threshold,
+                scheduling,
+            ),
+            AppArguments::TimeHistory { day } => time::handle_history(year, day),
+            AppArguments::Verify { selection } => verify::handle(year, &selection),
+            AppArguments::Download { day, force } => download::handle(year, day, force),
+            AppArguments::Read { day } => read::handle(year, day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                force,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day, force);
                 }
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
+                parallel,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+            } => solve::handle(year, day, release, dhat, parallel, submit),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(year, day, false);
+                        download::handle(year, day, false);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use crate::graph::{NodeId, Weight, WeightedGraph};
+use crate::{Board, Coord, Direction};
+
+/// Which states of a walker on a board become separate graph nodes
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum StateExpansion {
+    /// One node per passable cell
+    Position,
+    /// One node per passable cell and cardinal facing, turning in place is a move on its own
+    PositionAndFacing,
+}
+
+/// Transition between two states, passed to the cost function of `Maze::from_board`
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Move {
+    /// Step to an adjacent passable cell, `facing` is `None` without facing expansion
+    Step { from: Coord, to: Coord, facing: Option<Direction> },
+    /// Turn by 90 degrees without leaving the cell
+    Turn { at: Coord, from: Direction, to: Direction },
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct MazeNode {
+    pub tag: char,
+    pub coord: Coord,
+    pub facing: Option<Direction>,
+}
+
+/// Graph built from a board along with the lookup from board states to node ids
+pub struct Maze<W> {
+    pub graph: WeightedGraph<MazeNode, W>,
+    node_ids: HashMap<(Coord, Option<Direction>), NodeId>,
+}
+
+impl<W: Weight> Maze<W> {
+    /// Creates nodes for every cell with `is_passable` tag and connects them with moves.
+    /// `cost` returns the weight of a move, or `None` if the move is not allowed.
+    pub fn from_board(
+        board: &Board,
+        is_passable: impl Fn(char) -> bool,
+        expansion: StateExpansion,
+        cost: impl Fn(&Move) -> Option<W>,
+    ) -> Maze<W> {
+        let facings: Vec<Option<Direction>> = match expansion {
+            StateExpansion::Position => vec![None],
+            StateExpansion::PositionAndFacing => Direction::CARDINALS.map(Some).to_vec(),
+        };
+        let capacity = board.cells().filter(|(_, &tag)| is_passable(tag)).count() * facings.len();
+        let mut maze = Maze {
+            graph: WeightedGraph::with_capacity(capacity),
+            node_ids: HashMap::with_capacity(capacity),
+        };
+
+        for (coord, &tag) in board.cells().filter(|(_, &tag)| is_passable(tag)) {
+            for &facing in &facings {
+                let node_id = maze.graph.add_node(MazeNode { tag, coord, facing });
+                maze.node_ids.insert((coord, facing), node_id);
+            }
+        }
+
+        for (coord, _) in board.cells().filter(|(_, &tag)| is_passable(tag)) {
+            for (neighbour, _) in board.neighbours4(&coord).filter(|&(_, tag)| is_passable(tag)) {
+                maze.add_step(coord, neighbour, expansion, &cost);
+            }
+
+            if expansion == StateExpansion::PositionAndFacing {
+                for from in Direction::CARDINALS {
+                    for to in [from.rotate_left(), from.rotate_right()] {
+                        maze.add_move(&Move::Turn { at: coord, from, to }, &cost);
+                    }
+                }
+            }
+        }
+
+        return maze;
+    }
+
+    fn add_step(&mut self, from: Coord, to: Coord, expansion: StateExpansion, cost: &impl Fn(&Move) -> Option<W>) {
+        let facing = match expansion {
+            StateExpansion::Position => None,
+            StateExpansion::PositionAndFacing => Direction::from_vector(&(to - from)),
+        };
+        self.add_move(&Move::Step { from, to, facing }, cost);
+    }
+
+    fn add_move(&mut self, step: &Move, cost: &impl Fn(&Move) -> Option<W>) {
+        let (from_state, to_state) = match *step {
+            Move::Step { from, to, facing } => ((from, facing), (to, facing)),
+            Move::Turn { at, from, to } => ((at, Some(from)), (at, Some(to))),
+        };
+
+        if let Some(weight) = cost(step) {
+            self.graph.add_edge(self.node_ids[&from_state], self.node_ids[&to_state], weight);
+        }
+    }
+
+    /// Pass `None` as `facing` for mazes expanded by position only
+    pub fn node_id(&self, coord: &Coord, facing: Option<Direction>) -> Option<NodeId> {
+        return self.node_ids.get(&(*coord, facing)).copied();
+    }
+
+    pub fn node(&self, node_id: NodeId) -> &MazeNode {
+        return self.graph.node(node_id);
+    }
+
+    /// All nodes of cells with `tag`, regardless of facing
+    pub fn find_nodes_by_tag(&self, tag: char) -> Vec<NodeId> {
+        return self.graph.find_nodes(|node| node.tag == tag);
+    }
+
+    pub fn coords(&self, node_ids: &[NodeId]) -> Vec<Coord> {
+        return node_ids.iter().map(|&node_id| self.graph.node(node_id).coord).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Board {
+        return Board::from([
+            "#####",
+            "#S.E#",
+            "#.#.#",
+            "#####",
+        ].join("\n").as_str());
+    }
+
+    fn unit_cost(_: &Move) -> Option<u32> {
+        return Some(1);
+    }
+
+    #[test]
+    fn test_position_expansion() {
+        let maze = Maze::from_board(&board(), |tag| tag != '#', StateExpansion::Position, unit_cost);
+        assert_eq!(maze.graph.len(), 5);
+        let start = maze.node_id(&Coord::new(1, 1), None).unwrap();
+        let end = maze.find_nodes_by_tag('E');
+        assert_eq!(maze.graph.shortest_paths(start).distance(end[0]), Some(2));
+        assert_eq!(maze.node_id(&Coord::new(0, 0), None), None);
+    }
+
+    #[test]
+    fn test_position_and_facing_expansion() {
+        let cost = |step: &Move| match step {
+            Move::Step { .. } => Some(1),
+            Move::Turn { .. } => Some(1000),
+        };
+        let maze = Maze::from_board(&board(), |tag| tag != '#', StateExpansion::PositionAndFacing, cost);
+        assert_eq!(maze.graph.len(), 20);
+
+        let start = maze.node_id(&Coord::new(1, 1), Some(Direction::East)).unwrap();
+        let paths = maze.graph.shortest_paths(start);
+        let end_facing_east = maze.node_id(&Coord::new(1, 3), Some(Direction::East)).unwrap();
+        let below_end = maze.node_id(&Coord::new(2, 3), Some(Direction::South)).unwrap();
+        assert_eq!(paths.distance(end_facing_east), Some(2));
+        assert_eq!(paths.distance(below_end), Some(1003));
+        assert_eq!(maze.node(below_end).tag, '.');
+    }
+
+    #[test]
+    fn test_disallowed_moves() {
+        let cost = |step: &Move| match step {
+            Move::Step { from, to, .. } if to.y < from.y => None,
+            _ => Some(1u32),
+        };
+        let maze = Maze::from_board(&board(), |tag| tag != '#', StateExpansion::Position, cost);
+        let end = maze.node_id(&Coord::new(1, 3), None).unwrap();
+        let start = maze.node_id(&Coord::new(1, 1), None).unwrap();
+        assert_eq!(maze.graph.shortest_paths(end).distance(start), None);
+    }
+}
@@ -0,0 +1,62 @@
+/// Helpers for embarrassingly parallel checks in solutions.
+/// With the `parallel` feature enabled the work is split across all available cores
+/// using scoped std threads, otherwise everything runs serially on the current thread.
+use std::iter::Sum;
+
+/// Applies `f` to every item, the output order matches the input order
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    #[cfg(feature = "parallel")]
+    {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = items.len().div_ceil(threads).max(1);
+
+        return std::thread::scope(|scope| {
+            let handles: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(|| chunk.iter().map(&f).collect::<Vec<R>>()))
+                .collect();
+
+            return handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect();
+        });
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
+
+pub fn par_sum<T: Sync, R: Send + Sum>(items: &[T], f: impl Fn(&T) -> R + Sync) -> R {
+    return par_map(items, f).into_iter().sum();
+}
+
+/// Number of items for which `predicate` holds
+pub fn par_count<T: Sync>(items: &[T], predicate: impl Fn(&T) -> bool + Sync) -> usize {
+    return par_sum(items, |item| predicate(item) as usize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_map_keeps_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let actual = par_map(&items, |x| x * 2);
+        assert_eq!(actual, items.iter().map(|x| x * 2).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_par_map_empty() {
+        let items: Vec<u64> = Vec::new();
+        assert_eq!(par_map(&items, |x| x + 1), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_par_sum_and_count() {
+        let items: Vec<u64> = (1..=100).collect();
+        assert_eq!(par_sum(&items, |&x| x), 5050);
+        assert_eq!(par_count(&items, |x| x % 3 == 0), 33);
+    }
+}
@@ -0,0 +1,243 @@
+/// Known-correct answers, used to verify solutions after refactoring.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Stored per year, e.g. `./data/2024/answers.json`.
+fn file_path(year: Year) -> String {
+    format!("./data/{year}/answers.json")
+}
+
+/// Verified answers of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Verified answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// No verified answer is stored for the part.
+    Unknown,
+}
+
+impl Verdict {
+    /// Mark printed next to a part, empty if there is nothing to compare against.
+    pub fn mark(self) -> String {
+        match self {
+            Verdict::Correct => format!(" {ANSI_BOLD}✔{ANSI_RESET}"),
+            Verdict::Incorrect => format!(" {ANSI_BOLD}✘{ANSI_RESET}"),
+            Verdict::Unknown => String::new(),
+        }
+    }
+}
+
+impl Answers {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Reads answers from the JSON file. If not present, returns no answers.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(file_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn expected(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compares `actual` against the stored answer, a missing `actual` is incorrect if an answer is known.
+    pub fn verify(&self, day: Day, part: u8, actual: Option<&str>) -> Verdict {
+        match self.expected(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if Some(expected) == actual => Verdict::Correct,
+            Some(_) => Verdict::Incorrect,
+        }
+    }
+
+    /// Stores `answer` as the verified answer of a part, replacing any previous one.
+    pub fn insert(&mut self, day: Day, part: u8, answer: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let entry = &mut self.data[index];
+        match part {
+            1 => entry.part_1 = Some(answer.into()),
+            2 => entry.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match answer {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::day;
+
+    fn get_mock_answers() -> Answers {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "11", "part_2": null }, { "day": "16", "part_1": "7036", "part_2": "45" }] }"#;
+        Answers::try_from(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn reads_expected_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.expected(day!(1), 1), Some("11"));
+        assert_eq!(answers.expected(day!(1), 2), None);
+        assert_eq!(answers.expected(day!(16), 2), Some("45"));
+        assert_eq!(answers.expected(day!(2), 1), None);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(16), 1, Some("7036")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(day!(16), 1, Some("7037")),
+            Verdict::Incorrect
+        );
+        assert_eq!(answers.verify(day!(16), 2, None), Verdict::Incorrect);
+        assert_eq!(answers.verify(day!(1), 2, Some("31")), Verdict::Unknown);
+    }
+
+    #[test]
+    fn inserts_answers() {
+        let mut answers = get_mock_answers();
+        answers.insert(day!(1), 2, "31");
+        answers.insert(day!(6), 1, "41");
+        assert_eq!(answers.expected(day!(1), 2), Some("31"));
+        assert_eq!(answers.expected(day!(6), 1), Some("41"));
+        assert_eq!(answers.data[1].day, day!(6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "data": [{ "part_1": "1" }] }"#.to_string()).unwrap();
+    }
+}
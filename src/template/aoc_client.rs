@@ -0,0 +1,288 @@
+/// Built-in HTTP client for adventofcode.com, an alternative to the "aoc-cli" wrapper.
+/// Enabled with the `aoc-client` feature. The base URL can be changed with `AOC_BASE_URL`,
+/// e.g. to point it at a local stand-in server.
+use std::{env, fmt::Display, fs, path::PathBuf};
+
+use crate::template::{puzzle::articles, Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AocClientError {
+    /// No session cookie in `AOC_SESSION` or the session file.
+    MissingSession,
+    /// The session cookie was rejected, it is probably expired.
+    Unauthorized,
+    /// The puzzle of the day is not available yet.
+    NotUnlocked,
+    RateLimited,
+    UnexpectedStatus(u16),
+    Transport(String),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found in AOC_SESSION or ~/.adventofcode.session."
+            ),
+            AocClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, try a fresh one.")
+            }
+            AocClientError::NotUnlocked => write!(f, "the puzzle is not unlocked yet."),
+            AocClientError::RateLimited => write!(f, "too many requests, try again later."),
+            AocClientError::UnexpectedStatus(status) => {
+                write!(f, "unexpected HTTP status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: Year,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Configures the client for `year` from `AOC_BASE_URL` and the session cookie.
+    /// The session is read from `AOC_SESSION`, or else from the file in `AOC_SESSION_FILE`,
+    /// which defaults to `~/.adventofcode.session` like aoc-cli.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(session_file_path()?).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(AocClientError::MissingSession)?;
+
+        Ok(AocClient::new(&base_url, &session, year))
+    }
+
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Full HTML page of the puzzle description, including part two once it is unlocked.
+    pub fn puzzle_html(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_url(day))
+    }
+
+    /// Posts an answer and returns the text of the response message.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        let html = read_response(response)?;
+        Ok(extract_article_text(&html))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call();
+        read_response(response)
+    }
+}
+
+fn session_file_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        return Some(PathBuf::from(path));
+    }
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+    Some(PathBuf::from(home).join(".adventofcode.session"))
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(error_from_status(status, &body))
+        }
+        Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+fn error_from_status(status: u16, body: &str) -> AocClientError {
+    match status {
+        // an invalid session yields 400 or 500 with a login hint instead of a 401.
+        400 | 401 | 403 | 500 if body.contains("log in") => AocClientError::Unauthorized,
+        401 | 403 => AocClientError::Unauthorized,
+        404 => AocClientError::NotUnlocked,
+        429 => AocClientError::RateLimited,
+        _ => AocClientError::UnexpectedStatus(status),
+    }
+}
+
+/// Text of all `<article>` elements with tags removed, or of the whole page if there are none.
+/// Puzzle pages hold one article per unlocked part, submission responses a single one.
+pub fn extract_article_text(html: &str) -> String {
+    articles(html)
+        .iter()
+        .map(|article| strip_tags(article).trim().to_string())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{extract_article_text, AocClient, AocClientError};
+    use crate::{day, year};
+
+    /// Serves a single request with `status` and `body`, returns the base URL and the raw request.
+    fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (base_url, server) = serve_once(200, "1 2 3\n");
+        let client = AocClient::new(&base_url, "abc\n", year!(2024));
+        assert_eq!(client.input(day!(1)).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn posts_answers() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><p>That's the <em>right</em> answer!</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc", year!(2024));
+        assert_eq!(
+            client.submit(day!(7), 2, "42").unwrap(),
+            "That's the right answer!"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/7/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let cases = [
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+                AocClientError::Unauthorized,
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+                AocClientError::NotUnlocked,
+            ),
+            (429, "", AocClientError::RateLimited),
+            (502, "", AocClientError::UnexpectedStatus(502)),
+        ];
+
+        for (status, body, expected) in cases {
+            let (base_url, server) = serve_once(status, body);
+            let client = AocClient::new(&base_url, "abc", year!(2024));
+            assert_eq!(client.input(day!(1)), Err(expected));
+            server.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn extracts_article_text() {
+        let html =
+            "<html><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>a</p></article></html>";
+        assert_eq!(extract_article_text(html), "--- Day 1 ---a");
+        let two_parts = "<article><p>one</p></article><p>x</p><article><p>two</p></article>";
+        assert_eq!(extract_article_text(two_parts), "one\n\ntwo");
+        assert_eq!(extract_article_text("<p>plain</p>"), "plain");
+    }
+}
@@ -1,5 +1,43 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::answers::Answers;
+use crate::template::run_multi::{child_commands, is_same_build, print_summary, run_multi};
+use crate::template::{DaySelection, Scheduling, Year};
+
+/// Runs the selected days of `year`, every day by default, and prints a summary of all parts.
+/// Exits with a non-zero code if any part panicked, timed out or does not match its verified answer.
+pub fn handle(
+    year: Year,
+    selection: &DaySelection,
+    is_release: bool,
+    is_parallel: bool,
+    scheduling: Scheduling,
+) {
+    // the alias always builds with `--release`, a different build runs the whole command again instead of every day.
+    if cfg!(feature = "in-process")
+        && !scheduling.is_isolated()
+        && !is_same_build(is_release, is_parallel)
+    {
+        match child_commands::rerun_in_process(is_release, is_parallel) {
+            Ok(code) => process::exit(code),
+            Err(e) => {
+                eprintln!("Failed to run in-process: {e:?}");
+                process::exit(1);
+            }
+        }
+    }
+
+    let days = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        }
+    };
+
+    let run = run_multi(year, &days, is_release, false, is_parallel, scheduling);
+
+    if print_summary(&run, &Answers::read_from_file(year)) {
+        process::exit(1);
+    }
 }
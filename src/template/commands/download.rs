@@ -1,14 +1,86 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_cli, commands::scaffold, Day, Year};
+#[cfg(feature = "aoc-client")]
+use crate::template::{aoc_client::AocClient, puzzle::Puzzle};
 use std::process;
+#[cfg(feature = "aoc-client")]
+use std::{fs, path::Path};
 
-pub fn handle(day: Day) {
+/// Whether a cached input should be kept instead of downloading it again.
+fn keep_cached_input(year: Year, day: Day, force: bool) -> bool {
+    let input_path = aoc_cli::get_input_path(year, day);
+    let keep = !force && scaffold::has_content(&input_path);
+
+    if keep {
+        println!("Skipped input download, kept cached \"{input_path}\". Use --force to download it again.");
+    }
+
+    keep
+}
+
+#[cfg(not(feature = "aoc-client"))]
+pub fn handle(year: Year, day: Day, force: bool) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = aoc_cli::download(year, day, keep_cached_input(year, day, force)) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "aoc-client")]
+pub fn handle(year: Year, day: Day, force: bool) {
+    let skip_input = keep_cached_input(year, day, force);
+
+    let result = AocClient::from_env(year).and_then(|client| {
+        let input = if skip_input {
+            None
+        } else {
+            Some(client.input(day)?)
+        };
+        let puzzle = client.puzzle_html(day)?;
+        Ok((input, puzzle))
+    });
+
+    let (input, html) = match result {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("failed to download day {day}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = aoc_cli::get_input_path(year, day);
+    let puzzle_path = aoc_cli::get_puzzle_path(year, day);
+    let puzzle = Puzzle::from_html(&html);
+
+    let files = [
+        ("input", &input_path, input),
+        ("puzzle", &puzzle_path, Some(puzzle.markdown.clone())),
+    ];
+
+    println!("---");
+    for (kind, path, content) in files {
+        let Some(content) = content else {
+            continue;
+        };
+
+        // the data folders of a year do not exist before its first day is scaffolded.
+        if let Some(parent) = Path::new(path).parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                eprintln!("failed to create \"{}\": {e}", parent.display());
+                process::exit(1);
+            }
+        }
+
+        if let Err(e) = fs::write(path, content) {
+            eprintln!("failed to write \"{path}\": {e}");
+            process::exit(1);
+        }
+        println!("🎄 Successfully wrote {kind} to \"{path}\".");
+    }
+
+    scaffold::populate_from_puzzle(year, day, &puzzle);
+}
@@ -1,15 +1,31 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+#[cfg(not(feature = "aoc-client"))]
+use crate::template::aoc_cli;
+#[cfg(feature = "aoc-client")]
+use crate::template::{aoc_client::AocClient, puzzle::Puzzle};
+use crate::template::{Day, Year};
 
-pub fn handle(day: Day) {
+#[cfg(not(feature = "aoc-client"))]
+pub fn handle(year: Year, day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "aoc-client")]
+pub fn handle(year: Year, day: Day) {
+    match AocClient::from_env(year).and_then(|client| client.puzzle_html(day)) {
+        Ok(html) => println!("{}", Puzzle::from_html(&html).markdown),
+        Err(e) => {
+            eprintln!("failed to read day {day}: {e}");
+            process::exit(1);
+        }
+    }
+}
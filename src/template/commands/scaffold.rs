@@ -1,10 +1,11 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::template::Day;
+use crate::template::puzzle::{fill_expected_answer, use_part_example, Puzzle};
+use crate::template::{Day, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -19,6 +20,11 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
+/// Whether `path` exists and contains more than whitespace.
+pub fn has_content(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|x| !x.trim().is_empty())
+}
+
 fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
@@ -27,10 +33,18 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let module_path = format!("src/bin/{year}-{day}.rs");
+
+    // the data folders of a year do not exist before its first day is scaffolded.
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{year}/{folder}")) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -54,26 +68,100 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // inputs and examples are never truncated, they may already be downloaded or filled in.
+    for (kind, path) in [("input", &input_path), ("example", &example_path)] {
+        if has_content(path) {
+            println!("Kept existing {kind} file \"{path}\"");
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+
+        match create_file(path) {
+            Ok(_) => {
+                println!("Created empty {kind} file \"{path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to create {kind} file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    println!("---");
+    if Year::from_env() == Some(year) {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
+}
+
+/// Fills empty example files and the placeholder test assertions from a downloaded puzzle.
+/// Part two gets its own `{day}-2.txt` example if it differs from the one of part one.
+pub fn populate_from_puzzle(year: Year, day: Day, puzzle: &Puzzle) {
+    let module_path = format!("src/bin/{year}-{day}.rs");
+    let mut module = fs::read_to_string(&module_path).ok();
+    let shared_example = puzzle.part(1).and_then(|part| part.examples.first());
+
+    // the day may be downloaded before it is scaffolded.
+    if let Err(e) = fs::create_dir_all(format!("data/{year}/examples")) {
+        eprintln!("Failed to create data folder: {e}");
+        return;
+    }
+
+    for part in [1, 2] {
+        let Some(puzzle_part) = puzzle.part(part) else {
+            continue;
+        };
+
+        let example = puzzle_part.examples.first();
+        let has_own_example = part == 2 && example.is_some() && example != shared_example;
+
+        let example_path = if has_own_example {
+            format!("data/{year}/examples/{day}-{part}.txt")
+        } else {
+            format!("data/{year}/examples/{day}.txt")
+        };
+
+        if let Some(example) = example {
+            if !has_content(&example_path) {
+                match fs::write(&example_path, example) {
+                    Ok(()) => println!("Wrote example to \"{}\"", &example_path),
+                    Err(e) => eprintln!("Failed to write example file: {e}"),
+                }
+            } else if fs::read_to_string(&example_path).ok().as_ref() != Some(example) {
+                println!("Kept existing example file \"{}\"", &example_path);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+
+        let Some(source) = module.as_mut() else {
+            continue;
+        };
+
+        if has_own_example {
+            if let Some(updated) = use_part_example(source, part) {
+                *source = updated;
+            }
+        }
+
+        // the template returns `Option<u32>`, so only numeric answers fit into the test.
+        let answer = puzzle_part
+            .answer
+            .as_ref()
+            .filter(|x| x.parse::<u64>().is_ok());
+
+        if let Some(answer) = answer {
+            if let Some(updated) = fill_expected_answer(source, part, answer) {
+                *source = updated;
+                println!(
+                    "Expecting {answer} for the part {part} example in \"{}\"",
+                    &module_path
+                );
+            }
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if let Some(source) = module {
+        if let Err(e) = fs::write(&module_path, source) {
+            eprintln!("Failed to update module file: {e}");
+        }
+    }
 }
@@ -1,34 +1,76 @@
 use std::collections::HashSet;
+use std::process;
 
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::stats::format_nanos;
+use crate::template::timings::{Change, Timings};
+use crate::template::timings_history::{self, HistoryEntry};
+use crate::template::{
+    all_days, readme_benchmarks, Day, DaySelection, Scheduling, Year, ANSI_BOLD, ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(
+    year: Year,
+    selection: &DaySelection,
+    run_all: bool,
+    store: bool,
+    parallel: bool,
+    compare_threshold: Option<f64>,
+    scheduling: Scheduling,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
+    let days_to_run: HashSet<Day> = if !selection.is_empty() {
+        match selection.resolve(year) {
+            Ok(days) => days,
+            Err(e) => {
+                eprintln!("Failed to select days: {e}");
+                process::exit(1);
             }
-        },
-        |day| HashSet::from([day]),
-    );
+        }
+    } else if run_all {
+        all_days().collect()
+    } else if compare_threshold.is_some() {
+        // when comparing, re-run the days that have stored timings.
+        stored_timings
+            .data
+            .iter()
+            .filter(|t| t.year == year)
+            .map(|t| t.day)
+            .collect()
+    } else {
+        // without a selection or the `--all` flag, filter out days that are fully benched.
+        all_days()
+            .filter(|day| !stored_timings.is_day_complete(year, *day))
+            .collect()
+    };
+
+    let timings = run_multi(year, &days_to_run, true, true, false, scheduling)
+        .timings
+        .unwrap();
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    if parallel {
+        // stored benchmarks always come from the serial run, the parallel one is only compared.
+        let parallel_timings = run_multi(year, &days_to_run, true, true, true, scheduling)
+            .timings
+            .unwrap();
+        print_parallel_comparison(&timings, &parallel_timings);
+    }
+
+    let has_regressions = compare_threshold
+        .is_some_and(|threshold| print_regressions(&stored_timings, &timings, threshold));
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        let history_entries = timings_history::entries_from_timings(&timings);
+        if let Err(e) = timings_history::append(&history_entries) {
+            eprintln!("Failed to append to benchmark history: {e}");
+        }
+
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +79,127 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Prints changes against the stored timings, returns whether any part regressed.
+fn print_regressions(stored: &Timings, current: &Timings, threshold: f64) -> bool {
+    let comparisons = stored.compare(current, threshold);
+
+    println!();
+    println!("{ANSI_BOLD}Compared to stored timings (threshold {threshold}%):{ANSI_RESET}");
+
+    if comparisons.is_empty() {
+        println!("No stored statistics to compare against.");
+        return false;
+    }
+
+    for comparison in &comparisons {
+        let label = match comparison.change {
+            Change::Regression => "regression",
+            Change::Improvement => "improvement",
+            Change::Unchanged => "unchanged",
+        };
+
+        println!(
+            "Day {} Part {}: {} -> {} ({:+.1}%, {label})",
+            comparison.day,
+            comparison.part,
+            format_nanos(comparison.baseline.median),
+            format_nanos(comparison.current.median),
+            comparison.change_percent,
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.change == Change::Regression)
+        .count();
+
+    if regressions > 0 {
+        eprintln!("{regressions} part(s) regressed by more than {threshold}%.");
+    }
+
+    regressions > 0
+}
+
+fn print_parallel_comparison(serial: &Timings, parallel: &Timings) {
+    println!();
+    println!("Serial vs parallel:");
+
+    for timing in &serial.data {
+        let Some(parallel_timing) = parallel.data.iter().find(|t| t.key() == timing.key()) else {
+            continue;
+        };
+
+        let parts = [
+            (&timing.part_1, &parallel_timing.part_1),
+            (&timing.part_2, &parallel_timing.part_2),
+        ];
+
+        for (i, (serial_part, parallel_part)) in parts.into_iter().enumerate() {
+            if let (Some(serial_part), Some(parallel_part)) = (serial_part, parallel_part) {
+                println!(
+                    "Day {} Part {}: serial {} / parallel {}",
+                    timing.day,
+                    i + 1,
+                    serial_part,
+                    parallel_part
+                );
+            }
+        }
+    }
+}
+
+/// Prints all recorded benchmarks of a day, followed by a sparkline of the medians per part.
+pub fn handle_history(year: Year, day: Day) {
+    let entries = match timings_history::read_day(year, day) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    if entries.is_empty() {
+        println!(
+            "No benchmark history for day {day} of {year}. Record some with `cargo time {day} --store`."
+        );
+        return;
+    }
+
+    println!("{ANSI_BOLD}{year} Day {day}{ANSI_RESET}");
+    println!("| Date | Commit | Part | Median | Min | P95 |");
+    println!("| :---: | :---: | :---: | :---: | :---: | :---: |");
+
+    for entry in &entries {
+        println!(
+            "| {} | {} | {} | {} | {} | {} |",
+            timings_history::format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry.part,
+            entry.stats.format_median(),
+            format_nanos(entry.stats.min),
+            format_nanos(entry.stats.p95),
+        );
+    }
+
+    println!();
+    for part in [1, 2] {
+        let part_entries: Vec<&HistoryEntry> = entries.iter().filter(|e| e.part == part).collect();
+        let (Some(first), Some(last)) = (part_entries.first(), part_entries.last()) else {
+            continue;
+        };
+
+        let medians: Vec<f64> = part_entries.iter().map(|e| e.stats.median).collect();
+        println!(
+            "Part {part}: {} {} -> {}",
+            timings_history::sparkline(&medians),
+            format_nanos(first.stats.median),
+            format_nanos(last.stats.median),
+        );
+    }
 }
@@ -0,0 +1,82 @@
+use std::process;
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::run_multi::{child_commands, DayStatus};
+use crate::template::{DaySelection, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Runs the selected days of `year` that have verified answers and compares their answers against `data/{year}/answers.json`.
+/// Exits with a non-zero code if any part does not match its verified answer.
+pub fn handle(year: Year, selection: &DaySelection) {
+    let answers = Answers::read_from_file(year);
+
+    let days = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        }
+    };
+
+    if answers.data.is_empty() {
+        eprintln!("No verified answers found in data/{year}/answers.json.");
+        process::exit(1);
+    }
+
+    let mut mismatches: Vec<String> = vec![];
+    let mut verified = 0;
+
+    let selected: Vec<_> = answers
+        .data
+        .iter()
+        .filter(|answer| days.contains(&answer.day))
+        .collect();
+
+    for answer in &selected {
+        let day = answer.day;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let (records, status) =
+            child_commands::run_solution(year, day, false, true, false).unwrap();
+        match status {
+            DayStatus::Failed(failure) => println!("✖ {failure}"),
+            DayStatus::MissingInput => println!("No input."),
+            _ => {}
+        }
+        println!();
+
+        for part in [1, 2] {
+            let actual = records
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.as_deref());
+
+            match answers.verify(day, part, actual) {
+                Verdict::Correct => verified += 1,
+                Verdict::Incorrect => mismatches.push(format!(
+                    "Day {day} Part {part}: expected {}, got {}",
+                    answers.expected(day, part).unwrap(),
+                    actual.unwrap_or("nothing")
+                )),
+                Verdict::Unknown => {}
+            }
+        }
+    }
+
+    println!(
+        "Verified {verified} part(s) across {} day(s).",
+        selected.len()
+    );
+
+    if !mismatches.is_empty() {
+        for mismatch in &mismatches {
+            eprintln!("{mismatch}");
+        }
+        eprintln!(
+            "{} part(s) do not match their verified answer.",
+            mismatches.len()
+        );
+        process::exit(1);
+    }
+}
@@ -1,42 +1,65 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+#[cfg(feature = "aoc-client")]
+pub mod aoc_client;
 pub mod commands;
+pub mod record;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use run_multi::Scheduling;
+pub use selection::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod selection;
+mod stats;
+mod submissions;
 mod timings;
+mod timings_history;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a year to a string. E.g. like `data/2024/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(year.to_string())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// The year is taken from the name of the solution file, e.g. `src/bin/2024-01.rs`.
+///
+/// Also declares the `SOLUTION` entry which `build.rs` uses to compile the solution into the main binary
+/// with the `in-process` feature, so multi-day commands can run it in-process (see `registry`).
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
@@ -52,17 +75,44 @@ macro_rules! solution {
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The year of the solution.
+        const YEAR: $crate::template::Year = $crate::template::Year::__from_file_path(file!());
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The registry entry of the solution.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                run_part: __run_part,
+            };
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[allow(dead_code)]
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_file("inputs", YEAR, DAY);
+            $( run_part($func, &input, YEAR, DAY, $part); )*
+        }
+
+        #[allow(dead_code)]
+        fn __run_part(
+            input: &str,
+            part: u8,
+            is_timed: bool,
+        ) -> Option<$crate::template::record::PartRecord> {
+            use $crate::template::runner::*;
+            $(
+                if part == $part {
+                    return Some(run_part_record($func, input, YEAR, DAY, $part, is_timed));
+                }
+            )*
+            None
         }
     };
 }
@@ -0,0 +1,388 @@
+/// A part of the puzzle description, there is one per unlocked part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// Contents of `<pre><code>` blocks introduced by a paragraph mentioning an example.
+    pub examples: Vec<String>,
+    /// Last emphasised code snippet of the part, which is where the expected example answer is shown.
+    pub answer: Option<String>,
+}
+
+/// Puzzle page converted to Markdown, along with the examples and expected answers of its parts.
+/// Only relies on the structure of adventofcode.com pages, so it is not a general HTML parser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub parts: Vec<PuzzlePart>,
+}
+
+impl Puzzle {
+    pub fn from_html(html: &str) -> Self {
+        let articles = articles(html);
+
+        Puzzle {
+            markdown: articles
+                .iter()
+                .map(|article| html_to_markdown(article))
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+            parts: articles.iter().map(|article| parse_part(article)).collect(),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PuzzlePart> {
+        self.parts.get(usize::from(part).checked_sub(1)?)
+    }
+}
+
+/// Contents of all `<article>` elements, or the whole page if there are none.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles: Vec<&str> = vec![];
+    let mut rest = html;
+
+    while let Some((article, after)) = rest
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        articles.push(article);
+        rest = after;
+    }
+
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    articles
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else {
+            let tag = tag.trim_end_matches('/');
+            let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attributes));
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = attributes.split_once(&format!("{name}=\""))?;
+    rest.split_once('"').map(|(value, _)| value)
+}
+
+/// Converts the contents of an article to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut code_depth = 0;
+    let mut links: Vec<Option<&str>> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) if in_pre || !text.trim().is_empty() || !text.contains('\n') => {
+                markdown.push_str(&decode_entities(text));
+            }
+            Token::Open("pre", _) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            _ if in_pre => {}
+            Token::Open("h2", _) => markdown.push_str("## "),
+            Token::Close("h2" | "p") => markdown.push_str("\n\n"),
+            Token::Open("li", _) => markdown.push_str("- "),
+            Token::Close("li" | "ul") => markdown.push('\n'),
+            Token::Open("br", _) => markdown.push('\n'),
+            Token::Open("code", _) => {
+                code_depth += 1;
+                markdown.push('`');
+            }
+            Token::Close("code") => {
+                code_depth -= 1;
+                markdown.push('`');
+            }
+            // emphasis has no effect inside inline code.
+            Token::Open("em", _) | Token::Close("em") if code_depth == 0 => markdown.push('*'),
+            Token::Open("a", attributes) => {
+                links.push(attribute(attributes, "href"));
+                markdown.push('[');
+            }
+            Token::Close("a") => {
+                let href = links.pop().flatten().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    markdown.trim().to_string()
+}
+
+fn parse_part(article: &str) -> PuzzlePart {
+    let mut examples = vec![];
+    let mut answer = None;
+
+    let mut paragraph = String::new();
+    let mut last_paragraph = String::new();
+    let mut pre_block: Option<String> = None;
+    let mut open_tags: Vec<&str> = vec![];
+
+    for token in tokenize(article) {
+        match token {
+            Token::Open(name, _) => {
+                if name == "pre" {
+                    pre_block = Some(String::new());
+                }
+                if name == "p" {
+                    paragraph.clear();
+                }
+                open_tags.push(name);
+            }
+            Token::Close(name) => {
+                if name == "pre" {
+                    if let Some(block) = pre_block.take() {
+                        if last_paragraph.to_lowercase().contains("example") {
+                            examples.push(block);
+                        }
+                    }
+                }
+                if name == "p" {
+                    last_paragraph = paragraph.clone();
+                }
+                if let Some(index) = open_tags.iter().rposition(|tag| *tag == name) {
+                    open_tags.truncate(index);
+                }
+            }
+            Token::Text(text) => {
+                let text = decode_entities(text);
+
+                if let Some(block) = pre_block.as_mut() {
+                    block.push_str(&text);
+                    continue;
+                }
+
+                paragraph.push_str(&text);
+
+                // answers are highlighted as `<code><em>answer</em></code>`.
+                if open_tags.ends_with(&["code", "em"]) {
+                    answer = Some(text);
+                }
+            }
+        }
+    }
+
+    PuzzlePart { examples, answer }
+}
+
+/// Replaces the placeholder assertion of the test for `part` with `answer`.
+/// Returns `None` if the test was already changed.
+pub fn fill_expected_answer(source: &str, part: u8, answer: &str) -> Option<String> {
+    let test_start = source.find(test_fn_name(part))?;
+    let placeholder = "assert_eq!(result, None);";
+    let offset = test_start + source[test_start..].find(placeholder)?;
+
+    // the placeholder has to belong to this test, not to a later one.
+    if source[test_start..offset].contains("#[test]") {
+        return None;
+    }
+
+    let mut result = source.to_string();
+    result.replace_range(
+        offset..offset + placeholder.len(),
+        &format!("assert_eq!(result, Some({answer}));"),
+    );
+    Some(result)
+}
+
+/// Makes the test for `part` read its own example file, e.g. `01-2.txt`.
+pub fn use_part_example(source: &str, part: u8) -> Option<String> {
+    let test_start = source.find(test_fn_name(part))?;
+    let shared = "read_file(\"examples\", YEAR, DAY)";
+    let offset = test_start + source[test_start..].find(shared)?;
+
+    if source[test_start..offset].contains("#[test]") {
+        return None;
+    }
+
+    let mut result = source.to_string();
+    result.replace_range(
+        offset..offset + shared.len(),
+        &format!("read_file_part(\"examples\", YEAR, DAY, {part})"),
+    );
+    Some(result)
+}
+
+fn test_fn_name(part: u8) -> &'static str {
+    if part == 1 {
+        "fn test_part_one()"
+    } else {
+        "fn test_part_two()"
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fill_expected_answer, html_to_markdown, use_part_example, Puzzle};
+
+    const PAGE: &str = r#"<html><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <a href="/2024/about">Chief</a> is <em>missing</em>.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Pair <code>1 &lt; 2</code> up.</p>
+<pre><code>not an example</code></pre>
+<p>In the example above, this is <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Here is a different example:</p>
+<pre><code>1
+2
+</code></pre>
+<ul><li>One</li><li>Two</li></ul>
+<p>So, the similarity score is <code><em>31</em></code>.</p>
+</article>
+</main></html>"#;
+
+    const TEMPLATE: &str = r#"
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+"#;
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let puzzle = Puzzle::from_html(PAGE);
+        assert_eq!(puzzle.parts.len(), 2);
+
+        let part_one = puzzle.part(1).unwrap();
+        assert_eq!(part_one.examples, vec!["3   4\n4   3\n".to_string()]);
+        assert_eq!(part_one.answer.as_deref(), Some("11"));
+
+        let part_two = puzzle.part(2).unwrap();
+        assert_eq!(part_two.examples, vec!["1\n2\n".to_string()]);
+        assert_eq!(part_two.answer.as_deref(), Some("31"));
+        assert_eq!(puzzle.part(3), None);
+    }
+
+    #[test]
+    fn converts_to_markdown() {
+        let puzzle = Puzzle::from_html(PAGE);
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "The [Chief](/2024/about) is *missing*.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "Pair `1 < 2` up.",
+            "",
+            "```",
+            "not an example",
+            "```",
+            "",
+            "In the example above, this is `11`!",
+            "",
+            "## --- Part Two ---",
+            "",
+            "Here is a different example:",
+            "",
+            "```",
+            "1",
+            "2",
+            "```",
+            "",
+            "- One",
+            "- Two",
+            "",
+            "So, the similarity score is `31`.",
+        ]
+        .join("\n");
+        assert_eq!(puzzle.markdown, expected);
+        assert_eq!(html_to_markdown("a &amp;&lt;b&gt;"), "a &<b>");
+    }
+
+    #[test]
+    fn fills_expected_answers() {
+        let source = fill_expected_answer(TEMPLATE, 2, "31").unwrap();
+        assert!(source.contains("assert_eq!(result, Some(31));"));
+        assert_eq!(source.matches("assert_eq!(result, None);").count(), 1);
+
+        let source = fill_expected_answer(&source, 1, "11").unwrap();
+        assert!(source.contains("assert_eq!(result, Some(11));"));
+        assert_eq!(fill_expected_answer(&source, 1, "12"), None);
+    }
+
+    #[test]
+    fn switches_to_part_examples() {
+        let source = use_part_example(TEMPLATE, 2).unwrap();
+        assert!(source.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 2))"
+        ));
+        assert!(source
+            .contains("part_one(&advent_of_code::template::read_file(\"examples\", YEAR, DAY))"));
+        assert_eq!(use_part_example(&source, 2), None);
+    }
+}
@@ -0,0 +1,205 @@
+/// Machine-readable result of a single solution part.
+/// Solution binaries print one record per part as a JSON line when called with `--json`,
+/// which is how `run_multi` collects answers and timings from child processes.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+
+pub const JSON_FLAG: &str = "--json";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+}
+
+impl PartRecord {
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// Returns `None` for lines that are not records, e.g. regular solution output.
+    pub fn from_json_line(line: &str) -> Option<Self> {
+        let json = JsonValue::from_str(line.trim()).ok()?;
+        PartRecord::try_from(&json).ok()
+    }
+}
+
+/// Why the solution of a day stopped before all of its parts finished.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Panicked(String),
+    TimedOut,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+            Failure::TimedOut => f.write_str("timed out"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|part| *part == 1.0 || *part == 2.0)
+            .ok_or("Expected record.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected record to have key `stats`.")?;
+
+        Ok(PartRecord {
+            part: part as u8,
+            answer: answer.cloned(),
+            stats: BenchStats::try_from(stats)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Failure> for JsonValue {
+    fn from(value: &Failure) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        match value {
+            Failure::Panicked(message) => {
+                map.insert("kind".into(), JsonValue::String("panicked".into()));
+                map.insert("message".into(), JsonValue::String(message.clone()));
+            }
+            Failure::TimedOut => {
+                map.insert("kind".into(), JsonValue::String("timed_out".into()));
+            }
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Failure {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected failure to be a JSON object.")?;
+
+        let kind = json
+            .get("kind")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected failure.kind to be a string.")?;
+
+        match kind.as_str() {
+            "panicked" => json
+                .get("message")
+                .and_then(|v| v.get::<String>())
+                .map(|message| Failure::Panicked(message.clone()))
+                .ok_or_else(|| "Expected failure.message to be a string.".into()),
+            "timed_out" => Ok(Failure::TimedOut),
+            _ => Err("Expected failure.kind to be `panicked` or `timed_out`.".into()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Failure, PartRecord};
+    use crate::template::stats::BenchStats;
+
+    #[test]
+    fn round_trips_records() {
+        let record = PartRecord {
+            part: 2,
+            answer: Some("@ ( ms\nx".into()),
+            stats: BenchStats::from_samples(&[
+                Duration::from_micros(74),
+                Duration::from_micros(80),
+            ]),
+        };
+        let line = record.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(PartRecord::from_json_line(&line), Some(record));
+    }
+
+    #[test]
+    fn handles_missing_answers() {
+        let record = PartRecord::from_json_line(
+            r#"{ "part": 1, "answer": null, "stats": { "min": 12, "median": 12, "mean": 12, "p95": 12, "stddev": 0, "samples": 1 } }"#,
+        )
+        .unwrap();
+        assert_eq!(record.answer, None);
+        assert_eq!(record.stats.format_median(), "12.0ns");
+    }
+
+    #[test]
+    fn ignores_other_lines() {
+        assert_eq!(
+            PartRecord::from_json_line("Part 1: 0 (74.13ns @ 100000 samples)"),
+            None
+        );
+        assert_eq!(PartRecord::from_json_line("[1, 2]"), None);
+        assert_eq!(
+            PartRecord::from_json_line(
+                r#"{ "part": 3, "answer": null, "stats": { "min": 0, "median": 0, "mean": 0, "p95": 0, "stddev": 0, "samples": 1 } }"#
+            ),
+            None
+        );
+        assert_eq!(
+            PartRecord::from_json_line(r#"{ "part": 1, "answer": null, "nanos": 0 }"#),
+            None
+        );
+    }
+
+    #[test]
+    fn round_trips_failures() {
+        for failure in [Failure::Panicked("oh no".into()), Failure::TimedOut] {
+            let json = JsonValue::from(&failure);
+            assert_eq!(Failure::try_from(&json), Ok(failure));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+/// Solutions that are compiled into the main binary, so `all` and `time` can run them in a single process.
+/// Every `solution!` declares a `SOLUTION` entry, with the `in-process` feature `build.rs` collects them
+/// and `main` registers them on start.
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::{Once, OnceLock},
+};
+
+use crate::template::record::{Failure, PartRecord};
+use crate::template::{Day, Year};
+
+static SOLUTIONS: OnceLock<Vec<Solution>> = OnceLock::new();
+
+thread_local! {
+    /// Whether a panic on this thread is caught and reported as failure.
+    static IS_CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Runs one part against an input, `None` if the solution does not implement the part.
+    pub run_part: fn(&str, u8, bool) -> Option<PartRecord>,
+}
+
+impl Solution {
+    /// Runs all parts against the input and returns the records of the parts that finished.
+    /// Like in a solution binary, a panic stops the run and is returned as failure.
+    pub fn run(&self, input: &str, is_timed: bool) -> (Vec<PartRecord>, Option<Failure>) {
+        let mut records = vec![];
+
+        let result = {
+            let _guard = CatchPanicsGuard::new();
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                for part in [1, 2] {
+                    if let Some(record) = (self.run_part)(input, part, is_timed) {
+                        records.push(record);
+                    }
+                }
+            }))
+        };
+
+        let failure = result.err().map(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Failure::Panicked(message)
+        });
+
+        (records, failure)
+    }
+}
+
+/// Keeps the panic hook from printing panics of the current thread while it exists, they are reported as failure.
+/// Panics of other threads are still passed on to the previous hook.
+struct CatchPanicsGuard;
+
+impl CatchPanicsGuard {
+    fn new() -> Self {
+        static INSTALL_HOOK: Once = Once::new();
+
+        INSTALL_HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !IS_CATCHING_PANICS.get() {
+                    hook(info);
+                }
+            }));
+        });
+
+        IS_CATCHING_PANICS.set(true);
+        CatchPanicsGuard
+    }
+}
+
+impl Drop for CatchPanicsGuard {
+    fn drop(&mut self) {
+        IS_CATCHING_PANICS.set(false);
+    }
+}
+
+/// Registers the solutions of the running binary. Only the first call has an effect.
+pub fn register(solutions: Vec<Solution>) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+pub fn find(year: Year, day: Day) -> Option<Solution> {
+    SOLUTIONS
+        .get()?
+        .iter()
+        .find(|s| s.year == year && s.day == day)
+        .copied()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{find, register, Solution};
+    use crate::template::record::{Failure, PartRecord};
+    use crate::template::stats::BenchStats;
+    use crate::{day, year};
+
+    fn run_part(_input: &str, _part: u8, _is_timed: bool) -> Option<PartRecord> {
+        None
+    }
+
+    #[test]
+    fn finds_registered_solutions() {
+        register(vec![Solution {
+            year: year!(2015),
+            day: day!(3),
+            run_part,
+        }]);
+
+        assert_eq!(find(year!(2015), day!(3)).unwrap().day, day!(3));
+        assert!(find(year!(2016), day!(3)).is_none());
+        assert!(find(year!(2015), day!(4)).is_none());
+    }
+
+    #[test]
+    fn keeps_records_of_parts_before_a_panic() {
+        fn run_part_one(_input: &str, part: u8, _is_timed: bool) -> Option<PartRecord> {
+            assert!(part == 1, "part two is not implemented");
+            Some(PartRecord {
+                part,
+                answer: Some("1".into()),
+                stats: BenchStats::from_samples(&[Duration::from_nanos(1)]),
+            })
+        }
+
+        let solution = Solution {
+            year: year!(2015),
+            day: day!(1),
+            run_part: run_part_one,
+        };
+
+        let (records, failure) = solution.run("", false);
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            failure,
+            Some(Failure::Panicked("part two is not implemented".into()))
+        );
+    }
+}
@@ -1,55 +1,408 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    fs, io,
+    path::Path,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::answers::{Answers, Verdict};
+use crate::template::aoc_cli::get_input_path;
+use crate::template::commands::scaffold::has_content;
+use crate::template::record::{Failure, PartRecord};
+use crate::template::{registry, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// How many days run at the same time, and how long each of them may take.
+#[derive(Clone, Copy, Debug)]
+pub struct Scheduling {
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Scheduling {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            timeout: None,
+        }
+    }
+}
+
+impl Scheduling {
+    /// Only child processes can be run side by side with buffered output, or be killed after a timeout.
+    pub fn is_isolated(&self) -> bool {
+        self.jobs > 1 || self.timeout.is_some()
+    }
+}
+
+/// How the solution of a day finished.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    Ok,
+    Failed(Failure),
+    NotScaffolded,
+    /// The day is scaffolded, but its input has not been downloaded yet.
+    MissingInput,
+}
+
+impl DayStatus {
+    fn failure(&self) -> Option<&Failure> {
+        match self {
+            DayStatus::Failed(failure) => Some(failure),
+            _ => None,
+        }
+    }
+
+    fn from_failure(failure: Option<Failure>) -> Self {
+        failure.map_or(DayStatus::Ok, DayStatus::Failed)
+    }
+}
 
-    let mut need_space = false;
+/// The outcome of running the solution of a single day.
+pub struct DayReport {
+    pub day: Day,
+    pub status: DayStatus,
+    pub records: Vec<PartRecord>,
+    pub elapsed: Duration,
+}
 
+/// The outcome of running the solutions of several days.
+/// `timings` are only collected for timed runs.
+pub struct MultiRun {
+    pub reports: Vec<DayReport>,
+    /// Time spent running the days, without building their binaries up front.
+    pub elapsed: Duration,
+    pub timings: Option<Timings>,
+}
+
+/// Runs the solutions of the given days and prints their output in day order.
+/// With more than one job or a timeout, the solution binaries are built once and then run as child processes.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_parallel: bool,
+    scheduling: Scheduling,
+) -> MultiRun {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    // the solution binaries are built up front, so the build is not part of the run time.
+    if scheduling.is_isolated() {
+        if let Err(e) = child_commands::build_solutions(year, &days, is_release, is_parallel) {
+            eprintln!("Failed to build solutions: {e:?}");
+            process::exit(1);
+        }
+    }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let timer = Instant::now();
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let reports = if scheduling.is_isolated() {
+        run_isolated(year, &days, is_release, is_timed, scheduling)
+    } else {
+        run_sequentially(year, &days, is_release, is_timed, is_parallel)
+    };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    let elapsed = timer.elapsed();
+
+    let timings = is_timed.then(|| {
+        let timings = Timings {
+            data: reports
+                .iter()
+                .filter(|report| !report.records.is_empty() || report.status.failure().is_some())
+                .map(|report| {
+                    let mut timing =
+                        child_commands::timing_from_records(&report.records, year, report.day);
+                    timing.failure = report.status.failure().cloned();
+                    timing
+                })
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        timings
+    });
+
+    MultiRun {
+        reports,
+        elapsed,
+        timings,
+    }
+}
+
+fn run_sequentially(
+    year: Year,
+    days: &[Day],
+    is_release: bool,
+    is_timed: bool,
+    is_parallel: bool,
+) -> Vec<DayReport> {
+    days.iter()
+        .enumerate()
+        .map(|(i, &day)| {
+            print_day_header(day, i > 0);
+
+            let timer = Instant::now();
+
+            // solutions compiled into this binary run in-process, unless they need a different profile or feature set.
+            let (records, status) = match (skipped_status(year, day), registry::find(year, day)) {
+                (Some(status), _) => (vec![], status),
+                (None, Some(solution)) if is_same_build(is_release, is_parallel) => {
+                    match fs::read_to_string(get_input_path(year, day)) {
+                        Ok(input) => {
+                            let (records, failure) = solution.run(&input, is_timed);
+                            (records, DayStatus::from_failure(failure))
+                        }
+                        Err(_) => (vec![], DayStatus::MissingInput),
+                    }
+                }
+                _ => child_commands::run_solution(year, day, is_timed, is_release, is_parallel)
+                    .unwrap(),
+            };
+
+            let report = DayReport {
+                day,
+                status,
+                records,
+                elapsed: timer.elapsed(),
+            };
+
+            print_status(&report);
+            report
+        })
+        .collect()
+}
+
+/// How a day is reported without running it, if it is not scaffolded or has no input yet.
+pub fn skipped_status(year: Year, day: Day) -> Option<DayStatus> {
+    if !Path::new(&get_path_for_bin(year, day)).exists() {
+        Some(DayStatus::NotScaffolded)
+    } else if !has_content(&get_input_path(year, day)) {
+        Some(DayStatus::MissingInput)
     } else {
         None
     }
 }
 
+/// Whether the solutions compiled into this binary were built with the requested profile and features.
+pub fn is_same_build(is_release: bool, is_parallel: bool) -> bool {
+    cfg!(feature = "in-process")
+        && is_release != cfg!(debug_assertions)
+        && is_parallel == cfg!(feature = "parallel")
+}
+
+/// Runs up to `jobs` solution binaries built by `build_solutions` at a time, each of them is killed
+/// when it exceeds the timeout. Their output is buffered and printed as soon as all previous days have been printed.
+fn run_isolated(
+    year: Year,
+    days: &[Day],
+    is_release: bool,
+    is_timed: bool,
+    scheduling: Scheduling,
+) -> Vec<DayReport> {
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..scheduling.jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next_index = &next_index;
+
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+
+                let timer = Instant::now();
+                let output = child_commands::run_built_solution(
+                    year,
+                    day,
+                    is_timed,
+                    is_release,
+                    scheduling.timeout,
+                )
+                .unwrap();
+
+                if sender.send((index, output, timer.elapsed())).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut reports = Vec::with_capacity(days.len());
+
+        for (index, output, elapsed) in receiver {
+            pending.insert(index, (output, elapsed));
+
+            while let Some((output, elapsed)) = pending.remove(&reports.len()) {
+                let day = days[reports.len()];
+                print_day_header(day, !reports.is_empty());
+
+                let child_commands::BufferedOutput {
+                    stdout,
+                    stderr,
+                    records,
+                    status,
+                } = output;
+                print!("{stdout}");
+                eprint!("{stderr}");
+
+                let report = DayReport {
+                    day,
+                    status,
+                    records,
+                    elapsed,
+                };
+
+                print_status(&report);
+                reports.push(report);
+            }
+        }
+
+        reports
+    })
+}
+
+fn print_day_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+fn print_status(report: &DayReport) {
+    match &report.status {
+        DayStatus::Ok if report.records.is_empty() => println!("Not solved."),
+        DayStatus::Ok => {}
+        DayStatus::Failed(failure) => println!("✖ {failure}"),
+        DayStatus::NotScaffolded => println!("Not scaffolded."),
+        DayStatus::MissingInput => println!("No input."),
+    }
+}
+
+/// Status of a single part in the summary of a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Answered(String, Verdict),
+    /// The part ran, but returned `None`.
+    Unanswered(Verdict),
+    /// The solution panicked or timed out before the part finished.
+    Failed(Failure),
+    NotRun,
+}
+
+impl PartStatus {
+    pub fn from_report(report: &DayReport, part: u8, answers: &Answers) -> Self {
+        match report.records.iter().find(|record| record.part == part) {
+            Some(record) => {
+                let verdict = answers.verify(report.day, part, record.answer.as_deref());
+                match &record.answer {
+                    Some(answer) => PartStatus::Answered(answer.clone(), verdict),
+                    None => PartStatus::Unanswered(verdict),
+                }
+            }
+            None => match report.status.failure() {
+                Some(failure) => PartStatus::Failed(failure.clone()),
+                None => PartStatus::NotRun,
+            },
+        }
+    }
+
+    /// Parts that panicked, timed out or do not match their verified answer fail a run.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            PartStatus::Answered(_, Verdict::Incorrect)
+                | PartStatus::Unanswered(Verdict::Incorrect)
+                | PartStatus::Failed(_)
+        )
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Answered(answer, verdict) => {
+                let answer = if answer.contains('\n') { "▼" } else { answer };
+                write!(f, "{answer}{}", verdict.mark())
+            }
+            PartStatus::Unanswered(verdict) => write!(f, "✖ None{}", verdict.mark()),
+            PartStatus::Failed(Failure::Panicked(_)) => f.write_str("panic"),
+            PartStatus::Failed(Failure::TimedOut) => f.write_str("timeout"),
+            PartStatus::NotRun => f.write_str("-"),
+        }
+    }
+}
+
+/// Prints a table with the status of every part and how long each day took to run.
+/// Returns whether any part failed.
+pub fn print_summary(run: &MultiRun, answers: &Answers) -> bool {
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("| Day | Part 1 | Part 2 | Status | Time |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+
+    let mut failures = 0;
+
+    // unscaffolded days are already reported above, and there would be a lot of them early on.
+    for report in run
+        .reports
+        .iter()
+        .filter(|report| report.status != DayStatus::NotScaffolded)
+    {
+        let parts = [1, 2].map(|part| PartStatus::from_report(report, part, answers));
+        failures += parts.iter().filter(|part| part.is_failure()).count();
+
+        let status = match &report.status {
+            DayStatus::Ok => "ok".to_string(),
+            DayStatus::Failed(failure) => failure.to_string(),
+            DayStatus::NotScaffolded => "not scaffolded".to_string(),
+            DayStatus::MissingInput => "no input".to_string(),
+        };
+
+        println!(
+            "| {} | {} | {} | {} | {:.1?} |",
+            report.day, parts[0], parts[1], status, report.elapsed
+        );
+    }
+
+    println!(
+        "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.1?}{ANSI_RESET}",
+        run.elapsed
+    );
+
+    if failures > 0 {
+        eprintln!("{failures} part(s) panicked, timed out or do not match their verified answer.");
+    }
+
+    failures > 0
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     IO(io::Error),
 }
 
@@ -60,44 +413,115 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// Solution binaries are named after their year and day, e.g. `2024-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// All solutions also live in isolated binaries, which are used when a solution is not part of the registry.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the timing records.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_bin_name, get_path_for_bin, skipped_status, DayStatus, Error};
+    use crate::template::record::{Failure, PartRecord, JSON_FLAG};
+    use crate::template::{Day, Year};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        env,
+        io::{self, BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{Child, Command, ExitStatus, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// How often a child process is checked for completion while it can time out.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Output of a solution binary that was captured instead of forwarded.
+    pub struct BufferedOutput {
+        pub stdout: String,
+        pub stderr: String,
+        pub records: Vec<PartRecord>,
+        pub status: DayStatus,
+    }
+
+    /// Separates the panic message of a solution bin from the rest of its stderr.
+    /// Everything after the panic, like the message and a backtrace, is not forwarded.
+    #[derive(Default)]
+    struct PanicCapture {
+        message: Option<Vec<String>>,
+        is_complete: bool,
+    }
+
+    impl PanicCapture {
+        /// Returns whether the line belongs to a panic.
+        fn push(&mut self, line: &str) -> bool {
+            let Some(message) = &mut self.message else {
+                if line.starts_with("thread '") && line.contains(" panicked at ") {
+                    self.message = Some(vec![]);
+                    return true;
+                }
+                return false;
+            };
+
+            if line.starts_with("note: ") || line.starts_with("stack backtrace:") {
+                self.is_complete = true;
+            }
+
+            if !self.is_complete {
+                message.push(line.to_string());
+            }
+
+            true
+        }
+
+        fn into_failure(self, status: ExitStatus) -> Failure {
+            match self.message {
+                Some(message) if !message.is_empty() => Failure::Panicked(message.join("\n")),
+                _ => Failure::Panicked(format!("exited with {status}")),
+            }
+        }
+    }
+
+    /// Run the solution bin for a given day of a year, returns the records of its parts and how it finished.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        is_parallel: bool,
+    ) -> Result<(Vec<PartRecord>, DayStatus), Error> {
+        // skip command invocation for days that have not been scaffolded or downloaded yet.
+        if let Some(status) = skipped_status(year, day) {
+            return Ok((vec![], status));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        if is_parallel {
+            args.extend(["--features", "parallel"]);
+        }
+
+        // request machine-readable records in addition to the regular output.
+        args.push("--");
+        args.push(JSON_FLAG);
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing records from stdout and panics from stderr.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -111,82 +535,239 @@ pub mod child_commands {
         let mut output = vec![];
 
         let thread = thread::spawn(move || {
+            let mut panic = PanicCapture::default();
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                let line = line.unwrap();
+                if !panic.push(&line) {
+                    eprintln!("{line}");
+                }
             });
+            panic
         });
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            match PartRecord::from_json_line(&line) {
+                Some(record) => output.push(record),
+                None => println!("{line}"),
+            }
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let panic = thread.join().unwrap();
+        let exit_status = cmd.wait()?;
+
+        let status = if exit_status.success() {
+            DayStatus::Ok
+        } else {
+            DayStatus::Failed(panic.into_failure(exit_status))
+        };
 
-        Ok(output)
+        Ok((output, status))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Runs the command of this process again from a main binary with the requested profile and features,
+    /// so the solutions can still run in-process. Returns the exit code of the command.
+    pub fn rerun_in_process(is_release: bool, is_parallel: bool) -> Result<i32, Error> {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--quiet"]);
+
+        if is_release {
+            cmd.arg("--release");
+        }
+
+        if is_parallel {
+            cmd.args(["--features", "in-process,parallel"]);
+        } else {
+            cmd.args(["--features", "in-process"]);
+        }
+
+        cmd.arg("--").args(env::args().skip(1));
 
-        output
+        Ok(cmd.status()?.code().unwrap_or(1))
+    }
+
+    /// Build the solution bins of the given days once, so they can be run without invoking cargo.
+    pub fn build_solutions(
+        year: Year,
+        days: &[Day],
+        is_release: bool,
+        is_parallel: bool,
+    ) -> Result<(), Error> {
+        let bin_names: Vec<String> = days
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            .filter(|day| Path::new(&get_path_for_bin(year, **day)).exists())
+            .map(|day| get_bin_name(year, *day))
+            .collect();
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        // without any `--bin`, cargo would build every target.
+        if bin_names.is_empty() {
+            return Ok(());
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        let mut cmd = Command::new("cargo");
+        cmd.args(["build", "--quiet"]);
+
+        for bin_name in &bin_names {
+            cmd.args(["--bin", bin_name]);
+        }
+
+        if is_release {
+            cmd.arg("--release");
+        }
+
+        if is_parallel {
+            cmd.args(["--features", "parallel"]);
+        }
+
+        if cmd.status()?.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed)
+        }
+    }
+
+    /// Path of a solution bin that was built by `build_solutions`.
+    fn get_built_bin_path(year: Year, day: Day, is_release: bool) -> PathBuf {
+        let target_dir = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+        let profile = if is_release { "release" } else { "debug" };
+
+        Path::new(&target_dir).join(profile).join(format!(
+            "{}{}",
+            get_bin_name(year, day),
+            env::consts::EXE_SUFFIX
+        ))
+    }
+
+    /// Run a solution bin built by `build_solutions` and capture its output.
+    /// The bin is killed if it does not finish within `timeout`.
+    pub fn run_built_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<BufferedOutput, Error> {
+        let mut buffered = BufferedOutput {
+            stdout: String::new(),
+            stderr: String::new(),
+            records: vec![],
+            status: DayStatus::Ok,
+        };
+
+        // skip days that have not been scaffolded or downloaded yet.
+        if let Some(status) = skipped_status(year, day) {
+            buffered.status = status;
+            return Ok(buffered);
+        }
+
+        let mut cmd = Command::new(get_built_bin_path(year, day, is_release));
+        cmd.arg(JSON_FLAG);
+
+        if is_timed {
+            cmd.arg("--time");
+        }
+
+        let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+
+        let stdout = read_in_background(child.stdout.take().ok_or(Error::BrokenPipe)?);
+        let stderr = read_in_background(child.stderr.take().ok_or(Error::BrokenPipe)?);
+
+        let exit_status = wait_with_timeout(&mut child, timeout)?;
+
+        for line in stdout.join().unwrap().lines() {
+            match PartRecord::from_json_line(line) {
+                Some(record) => buffered.records.push(record),
+                None => {
+                    buffered.stdout.push_str(line);
+                    buffered.stdout.push('\n');
                 }
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
+        let mut panic = PanicCapture::default();
+        for line in stderr.join().unwrap().lines() {
+            if !panic.push(line) {
+                buffered.stderr.push_str(line);
+                buffered.stderr.push('\n');
+            }
+        }
 
-        timings
+        buffered.status = match exit_status {
+            None => DayStatus::Failed(Failure::TimedOut),
+            Some(exit_status) if exit_status.success() => DayStatus::Ok,
+            Some(exit_status) => DayStatus::Failed(panic.into_failure(exit_status)),
+        };
+
+        Ok(buffered)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    fn read_in_background(mut reader: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+        thread::spawn(move || {
+            let mut bytes = vec![];
+            let _ = reader.read_to_end(&mut bytes);
+            String::from_utf8_lossy(&bytes).into_owned()
+        })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    /// Waits for the child to exit, returns `None` if it was killed because it exceeded the timeout.
+    fn wait_with_timeout(
+        child: &mut Child,
+        timeout: Option<Duration>,
+    ) -> io::Result<Option<ExitStatus>> {
+        let Some(timeout) = timeout else {
+            return child.wait().map(Some);
+        };
+
+        let deadline = Instant::now() + timeout;
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        loop {
+            if let Some(exit_status) = child.try_wait()? {
+                return Ok(Some(exit_status));
+            }
 
-        Some((str_timing, parsed_timing))
+            if Instant::now() >= deadline {
+                // the child may have exited in the meantime, which is fine.
+                let _ = child.kill();
+                child.wait()?;
+                return Ok(None);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Collect the timings of a day from the records of its parts.
+    /// Parts without an answer are treated as not solved and have no timing.
+    pub fn timing_from_records(records: &[PartRecord], year: Year, day: Day) -> super::Timing {
+        let mut timing = super::Timing {
+            year,
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            total_nanos: 0_f64,
+            failure: None,
+        };
+
+        for record in records.iter().filter(|r| r.answer.is_some()) {
+            let stats = record.stats;
+            match record.part {
+                1 => {
+                    timing.part_1 = Some(stats.format_median());
+                    timing.part_1_stats = Some(stats);
+                }
+                2 => {
+                    timing.part_2 = Some(stats.format_median());
+                    timing.part_2_stats = Some(stats);
+                }
+                _ => continue,
+            }
+
+            timing.total_nanos += stats.median;
+        }
+
+        timing
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +786,184 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::{process::ExitStatus, time::Duration};
 
-        use crate::day;
+        use super::{timing_from_records, PanicCapture};
+
+        use crate::template::record::{Failure, PartRecord};
+        use crate::template::stats::BenchStats;
+        use crate::{day, year};
+
+        fn record(part: u8, answer: Option<&str>, nanos: &[u64]) -> PartRecord {
+            let samples: Vec<Duration> = nanos.iter().map(|n| Duration::from_nanos(*n)).collect();
+            PartRecord {
+                part,
+                answer: answer.map(String::from),
+                stats: BenchStats::from_samples(&samples),
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(1, Some("0"), &[70, 74, 90]),
+                    record(2, Some("10"), &[74_130_000]),
                 ],
+                year!(2024),
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns ± 9.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().min, 70.0);
+            assert_eq!(res.part_2_stats.unwrap().samples, 1);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn handles_patterns_in_answers() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(1, Some("@ @ @ ( ) ms"), &[2_000_000_000]),
+                    record(2, Some("10s (1ms @ 5 samples)"), &[100_000_000]),
                 ],
+                year!(2024),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+        fn captures_panic_messages() {
+            let mut panic = PanicCapture::default();
+            let stderr = [
+                "[src/bin/2024-16.rs:12:5] &node = 3",
+                "thread 'main' (5587) panicked at src/bin/2024-16.rs:40:30:",
+                "called `Option::unwrap()` on a `None` value",
+                "note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace",
+            ];
+            let forwarded: Vec<&str> = stderr
+                .into_iter()
+                .filter(|line| !panic.push(line))
+                .collect();
+
+            assert_eq!(forwarded, vec!["[src/bin/2024-16.rs:12:5] &node = 3"]);
+            assert_eq!(
+                panic.into_failure(ExitStatus::default()),
+                Failure::Panicked("called `Option::unwrap()` on a `None` value".into())
+            );
+        }
+
+        #[test]
+        fn handles_missing_parts() {
+            let res = timing_from_records(
+                &[record(1, None, &[5]), record(2, None, &[5])],
+                year!(2024),
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert!(res.part_1_stats.is_none());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{skipped_status, DayReport, DayStatus, PartStatus};
+    use crate::template::answers::{Answer, Answers, Verdict};
+    use crate::template::record::{Failure, PartRecord};
+    use crate::template::stats::BenchStats;
+    use crate::{day, year};
+
+    fn report(status: DayStatus, answers: &[Option<&str>]) -> DayReport {
+        DayReport {
+            day: day!(1),
+            status,
+            records: answers
+                .iter()
+                .zip(1..)
+                .map(|(answer, part)| PartRecord {
+                    part,
+                    answer: answer.map(String::from),
+                    stats: BenchStats::from_samples(&[Duration::from_nanos(1)]),
+                })
+                .collect(),
+            elapsed: Duration::from_millis(1),
+        }
+    }
+
+    fn answers() -> Answers {
+        Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("11".into()),
+                part_2: None,
+            }],
         }
     }
+
+    #[test]
+    fn verifies_answered_parts() {
+        let report = report(DayStatus::Ok, &[Some("11"), Some("31")]);
+        let part_1 = PartStatus::from_report(&report, 1, &answers());
+        let part_2 = PartStatus::from_report(&report, 2, &answers());
+
+        assert_eq!(part_1, PartStatus::Answered("11".into(), Verdict::Correct));
+        assert_eq!(part_2, PartStatus::Answered("31".into(), Verdict::Unknown));
+        assert!(!part_1.is_failure());
+        assert!(!part_2.is_failure());
+        assert_eq!(part_2.to_string(), "31");
+    }
+
+    #[test]
+    fn fails_wrong_and_missing_answers() {
+        let wrong = report(DayStatus::Ok, &[Some("12")]);
+        let missing = report(DayStatus::Ok, &[None, None]);
+
+        assert!(PartStatus::from_report(&wrong, 1, &answers()).is_failure());
+        assert!(PartStatus::from_report(&missing, 1, &answers()).is_failure());
+
+        let unknown = PartStatus::from_report(&missing, 2, &answers());
+        assert_eq!(unknown, PartStatus::Unanswered(Verdict::Unknown));
+        assert!(!unknown.is_failure());
+        assert_eq!(unknown.to_string(), "✖ None");
+    }
+
+    #[test]
+    fn fails_parts_after_a_panic() {
+        let failure = Failure::Panicked("oh no".into());
+        let report = report(DayStatus::Failed(failure.clone()), &[Some("11")]);
+
+        let part_2 = PartStatus::from_report(&report, 2, &answers());
+        assert_eq!(part_2, PartStatus::Failed(failure));
+        assert!(part_2.is_failure());
+        assert_eq!(part_2.to_string(), "panic");
+    }
+
+    #[test]
+    fn skips_parts_that_did_not_run() {
+        for status in [DayStatus::NotScaffolded, DayStatus::MissingInput] {
+            let report = report(status, &[]);
+            let part_1 = PartStatus::from_report(&report, 1, &answers());
+
+            assert_eq!(part_1, PartStatus::NotRun);
+            assert!(!part_1.is_failure());
+        }
+    }
+
+    #[test]
+    fn skips_days_without_solution_or_input() {
+        assert_eq!(
+            skipped_status(year!(2015), day!(25)),
+            Some(DayStatus::NotScaffolded)
+        );
+    }
 }
@@ -2,23 +2,66 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::Answers;
+#[cfg(not(feature = "aoc-client"))]
+use crate::template::aoc_cli;
+use crate::template::record::{PartRecord, JSON_FLAG};
+use crate::template::stats::{format_nanos, BenchStats};
+use crate::template::submissions::{Outcome, Submission, Submissions};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// For every `WARMUP_DIVISOR` bench iterations, one discarded iteration is run up front to warm up caches.
+const WARMUP_DIVISOR: u128 = 10;
+
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    let is_timed = env::args().any(|x| x == "--time");
+    let record = run_part_record(func, input, year, day, part, is_timed);
+
+    if env::args().any(|x| x == JSON_FLAG) {
+        println!("{}", record.to_json_line());
+    }
+
+    if let Some(answer) = record.answer {
+        submit_result(&answer, year, day, part);
+    }
+}
+
+/// Runs and prints a part like `run_part`, but returns its record instead of acting on the command-line.
+/// Used for solutions that are run in-process, see `registry`.
+pub fn run_part_record<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    is_timed: bool,
+) -> PartRecord {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats) = run_timed(func, input, is_timed, |result| {
+        print_result(result, &part_str, "");
+    });
+
+    let answer = result.as_ref().map(ToString::to_string);
+    let verdict = Answers::read_from_file(year).verify(day, part, answer.as_deref());
+    let duration_str = format!("{}{}", format_duration(&stats), verdict.mark());
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(&result, &part_str, &duration_str);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    PartRecord {
+        part,
+        answer,
+        stats,
     }
 }
 
@@ -28,8 +71,9 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,16 +85,17 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if is_timed {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&[base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Benches `func` after a number of warm-up iterations that are not included in the statistics.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -58,6 +103,11 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let warmup_iterations = cmp::max(bench_iterations / WARMUP_DIVISOR, 1);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,26 +117,20 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({})", stats.format_median())
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({} @ {} samples, min {}, p95 {})",
+            stats.format_median(),
+            stats.samples,
+            format_nanos(stats.min),
+            format_nanos(stats.p95)
+        )
     }
 }
 
@@ -127,12 +171,11 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. aoc-cli is installed, or the `aoc-client` feature is enabled.
+///  3. the answer is not already known to be wrong.
+///
+/// The judged outcome is recorded, correct answers are stored as verified answers.
+fn submit_result(answer: &str, year: Year, day: Day, part: u8) -> Option<Outcome> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +198,73 @@ fn submit_result<T: Display>(
         return None;
     }
 
+    let mut submissions = Submissions::read_from_file(year);
+
+    if let Some(reason) = submissions.refusal(day, part, answer) {
+        eprintln!("Not submitting {answer}: {reason}.");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = match submit_answer(year, day, part, answer) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            return None;
+        }
+    };
+
+    let Some(outcome) = Outcome::parse(&response) else {
+        eprintln!("Could not recognise the submission response, it was not recorded.");
+        return None;
+    };
+
+    submissions.push(Submission {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+    });
+    if let Err(e) = submissions.store_file(year) {
+        eprintln!("Failed to record submission: {e}");
+    }
+
+    if outcome == Outcome::Correct {
+        let mut answers = Answers::read_from_file(year);
+        answers.insert(day, part, answer);
+        if let Err(e) = answers.store_file(year) {
+            eprintln!("Failed to store verified answer: {e}");
+        }
+    }
+
+    Some(outcome)
+}
+
+#[cfg(not(feature = "aoc-client"))]
+fn submit_answer(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<String, aoc_cli::AocCommandError> {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = aoc_cli::submit(year, day, part, answer)?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(feature = "aoc-client")]
+fn submit_answer(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<String, crate::template::aoc_client::AocClientError> {
+    let response =
+        crate::template::aoc_client::AocClient::from_env(year)?.submit(day, part, answer)?;
+    println!("{response}");
+    Ok(response)
 }
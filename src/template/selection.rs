@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use crate::template::answers::Answers;
+use crate::template::{all_days, Day, Year};
+
+/// Days of a list of day numbers and ranges, e.g. `1-5,8,16`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayList(pub HashSet<Day>);
+
+/// Which days a multi-day command runs. All criteria that are set have to match.
+///
+/// ```
+/// # use advent_of_code::template::{DayList, DaySelection};
+/// // `cargo all 1-10 --unsolved`
+/// let selection = DaySelection {
+///     list: Some("1-10".parse::<DayList>().unwrap()),
+///     unsolved: true,
+///     ..DaySelection::default()
+/// };
+/// assert!(!selection.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DaySelection {
+    pub list: Option<DayList>,
+    /// Only days on or after this day.
+    pub since: Option<Day>,
+    /// Only days without verified answers for all of their parts.
+    pub unsolved: bool,
+    /// Only days with uncommitted changes in `src/bin`.
+    pub changed: bool,
+}
+
+impl DaySelection {
+    /// Returns whether no criteria were given, commands then fall back to their default days.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_none() && self.since.is_none() && !self.unsolved && !self.changed
+    }
+
+    /// Collects the selected days of `year`, every day if no criteria were given.
+    pub fn resolve(&self, year: Year) -> io::Result<HashSet<Day>> {
+        let changed = if self.changed {
+            changed_days(year)?
+        } else {
+            HashSet::new()
+        };
+
+        Ok(self.select(&Answers::read_from_file(year), &changed))
+    }
+
+    fn select(&self, answers: &Answers, changed: &HashSet<Day>) -> HashSet<Day> {
+        all_days()
+            .filter(|day| self.list.as_ref().is_none_or(|list| list.0.contains(day)))
+            .filter(|day| self.since.is_none_or(|since| *day >= since))
+            .filter(|day| !self.unsolved || !is_solved(answers, *day))
+            .filter(|day| !self.changed || changed.contains(day))
+            .collect()
+    }
+}
+
+/// Day 25 only has a single puzzle, the second star is given for free.
+fn is_solved(answers: &Answers, day: Day) -> bool {
+    answers.expected(day, 1).is_some() && (day == 25 || answers.expected(day, 2).is_some())
+}
+
+/// Days of `year` whose solution in `src/bin` is new or modified, according to `git status`.
+fn changed_days(year: Year) -> io::Result<HashSet<Day>> {
+    let output = Command::new("git")
+        .args([
+            "status",
+            "--porcelain",
+            "--untracked-files=all",
+            "--",
+            "src/bin",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(parse_changed_days(
+        &String::from_utf8_lossy(&output.stdout),
+        year,
+    ))
+}
+
+fn parse_changed_days(status: &str, year: Year) -> HashSet<Day> {
+    status
+        .lines()
+        // renamed files are listed as `R  old -> new`.
+        .filter_map(|line| line.get(3..)?.rsplit(" -> ").next())
+        .filter_map(|path| Path::new(path).file_stem()?.to_str())
+        .filter_map(|name| {
+            let (file_year, day) = name.split_once('-')?;
+            (file_year.parse::<Year>().ok()? == year)
+                .then(|| day.parse().ok())
+                .flatten()
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DayList {
+    type Err = DayListFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = HashSet::new();
+
+        for item in s.split(',').map(str::trim) {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let start: Day = start.trim().parse().map_err(|_| DayListFromStrError)?;
+                    let end: Day = end.trim().parse().map_err(|_| DayListFromStrError)?;
+
+                    if start > end {
+                        return Err(DayListFromStrError);
+                    }
+
+                    days.extend(all_days().filter(|day| *day >= start && *day <= end));
+                }
+                None => {
+                    days.insert(item.parse().map_err(|_| DayListFromStrError)?);
+                }
+            }
+        }
+
+        Ok(DayList(days))
+    }
+}
+
+/// An error which can be returned when parsing a [`DayList`].
+#[derive(Debug)]
+pub struct DayListFromStrError;
+
+impl Error for DayListFromStrError {}
+
+impl Display for DayListFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days between 1 and 25, like `1-5,8,16`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{parse_changed_days, DayList, DaySelection};
+    use crate::template::answers::{Answer, Answers};
+    use crate::{day, year};
+
+    #[test]
+    fn parses_day_lists() {
+        let list: DayList = "1-3, 8,16".parse().unwrap();
+        assert_eq!(
+            list.0,
+            HashSet::from([day!(1), day!(2), day!(3), day!(8), day!(16)])
+        );
+        assert_eq!("7".parse::<DayList>().unwrap().0, HashSet::from([day!(7)]));
+    }
+
+    #[test]
+    fn rejects_invalid_day_lists() {
+        assert!("".parse::<DayList>().is_err());
+        assert!("5-1".parse::<DayList>().is_err());
+        assert!("1-26".parse::<DayList>().is_err());
+        assert!("1,,2".parse::<DayList>().is_err());
+    }
+
+    #[test]
+    fn combines_criteria() {
+        let answers = Answers {
+            data: vec![
+                Answer {
+                    day: day!(10),
+                    part_1: Some("1".into()),
+                    part_2: Some("2".into()),
+                },
+                Answer {
+                    day: day!(11),
+                    part_1: Some("1".into()),
+                    part_2: None,
+                },
+                Answer {
+                    day: day!(25),
+                    part_1: Some("1".into()),
+                    part_2: None,
+                },
+            ],
+        };
+
+        let selection = DaySelection {
+            since: Some(day!(10)),
+            unsolved: true,
+            ..DaySelection::default()
+        };
+        let days = selection.select(&answers, &HashSet::new());
+        assert_eq!(days.len(), 14);
+        assert!(!days.contains(&day!(10)));
+        assert!(days.contains(&day!(11)));
+        assert!(!days.contains(&day!(25)));
+
+        let selection = DaySelection {
+            list: Some("1-12".parse().unwrap()),
+            changed: true,
+            ..DaySelection::default()
+        };
+        let changed = HashSet::from([day!(3), day!(20)]);
+        assert_eq!(
+            selection.select(&answers, &changed),
+            HashSet::from([day!(3)])
+        );
+    }
+
+    #[test]
+    fn selects_every_day_by_default() {
+        let selection = DaySelection::default();
+        assert!(selection.is_empty());
+        assert_eq!(
+            selection.select(&Answers::default(), &HashSet::new()).len(),
+            25
+        );
+    }
+
+    #[test]
+    fn parses_changed_days() {
+        let status = " M src/bin/2024-05.rs\n?? src/bin/2024-17.rs\nR  src/bin/2024-02.rs -> src/bin/2023-02.rs\n M src/bin/2023-04.rs\n";
+        assert_eq!(
+            parse_changed_days(status, year!(2024)),
+            HashSet::from([day!(5), day!(17)])
+        );
+        assert_eq!(
+            parse_changed_days(status, year!(2023)),
+            HashSet::from([day!(2), day!(4)])
+        );
+    }
+}
@@ -0,0 +1,169 @@
+/// Summary statistics of benchmark samples.
+/// All values are in nanoseconds, so they can be stored as JSON numbers without loss of precision.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+    pub samples: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics of `samples`, which must not be empty.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics of zero samples"
+        );
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let len = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len as f64;
+
+        let median = if len.is_multiple_of(2) {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2.0
+        } else {
+            nanos[len / 2]
+        };
+
+        // nearest-rank percentile.
+        let p95_rank = (len * 95).div_ceil(100).max(1);
+
+        BenchStats {
+            min: nanos[0],
+            median,
+            mean,
+            p95: nanos[p95_rank - 1],
+            stddev: variance.sqrt(),
+            samples: len as u128,
+        }
+    }
+
+    /// Median with standard deviation as spread, e.g. `74.1µs ± 2.3µs`.
+    /// Single samples have no spread, so only the duration is shown.
+    pub fn format_median(&self) -> String {
+        if self.samples <= 1 {
+            format_nanos(self.median)
+        } else {
+            format!(
+                "{} ± {}",
+                format_nanos(self.median),
+                format_nanos(self.stddev)
+            )
+        }
+    }
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            min: number("min")?,
+            median: number("median")?,
+            mean: number("mean")?,
+            p95: number("p95")?,
+            stddev: number("stddev")?,
+            samples: number("samples")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn from_nanos(nanos: &[u64]) -> BenchStats {
+        let samples: Vec<Duration> = nanos.iter().map(|n| Duration::from_nanos(*n)).collect();
+        BenchStats::from_samples(&samples)
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = from_nanos(&[5, 1, 4, 2, 3]);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 3.0);
+        assert_eq!(stats.p95, 5.0);
+        assert_eq!(stats.stddev, 2_f64.sqrt());
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn computes_median_of_even_samples() {
+        assert_eq!(from_nanos(&[4, 1, 2, 3]).median, 2.5);
+    }
+
+    #[test]
+    fn ignores_outliers_in_median_and_p95() {
+        let mut nanos = vec![100; 99];
+        nanos.push(1_000_000);
+        let stats = from_nanos(&nanos);
+        assert_eq!(stats.median, 100.0);
+        assert_eq!(stats.p95, 100.0);
+        assert!(stats.mean > 10_000.0);
+    }
+
+    #[test]
+    fn formats_median() {
+        assert_eq!(from_nanos(&[1500]).format_median(), "1.5µs");
+        assert_eq!(from_nanos(&[1000, 3000]).format_median(), "2.0µs ± 1.0µs");
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = from_nanos(&[10, 20, 30]);
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json), Ok(stats));
+    }
+}
@@ -0,0 +1,297 @@
+/// Log of answers submitted to adventofcode.com and how they were judged.
+/// Used to refuse submissions that are already known to be wrong before they cost a timeout.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+/// Stored per year, e.g. `./data/2024/submissions.json`.
+fn file_path(year: Year) -> String {
+    format!("./data/{year}/submissions.json")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+}
+
+impl Outcome {
+    /// Parses the response text of a submission, `None` if the response is not recognised.
+    pub fn parse(response: &str) -> Option<Self> {
+        let response = response.to_lowercase();
+
+        if response.contains("that's the right answer") {
+            Some(Outcome::Correct)
+        } else if response.contains("too recently") || response.contains("you have to wait") {
+            Some(Outcome::RateLimited)
+        } else if response.contains("answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if response.contains("answer is too low") {
+            Some(Outcome::TooLow)
+        } else if response.contains("not the right answer") {
+            Some(Outcome::Incorrect)
+        } else {
+            None
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::RateLimited => "rate_limited",
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "rate_limited" => Ok(Outcome::RateLimited),
+            _ => Err(format!("unknown submission outcome `{s}`.")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Reads submissions from the JSON file. If not present, returns no submissions.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(file_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.data.push(submission);
+    }
+
+    /// Returns the reason why submitting `answer` is pointless, or `None` if it may be submitted.
+    /// Numeric answers are also checked against the tightest known too-high and too-low bounds.
+    pub fn refusal(&self, day: Day, part: u8, answer: &str) -> Option<String> {
+        let submissions = self.data.iter().filter(|s| s.day == day && s.part == part);
+        let number = answer.parse::<i128>().ok();
+
+        for submission in submissions {
+            let known = submission.answer.parse::<i128>().ok();
+
+            let reason = match (submission.outcome, number, known) {
+                (Outcome::Correct, ..) => {
+                    format!("{} was already accepted as correct", submission.answer)
+                }
+                (Outcome::RateLimited, ..) => continue,
+                _ if submission.answer == answer => {
+                    format!("it was already judged {}", submission.outcome.as_str())
+                }
+                (Outcome::TooHigh, Some(number), Some(known)) if number >= known => {
+                    format!("{known} was already judged too high")
+                }
+                (Outcome::TooLow, Some(number), Some(known)) if number <= known => {
+                    format!("{known} was already judged too low")
+                }
+                _ => continue,
+            };
+
+            return Some(reason);
+        }
+
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.outcome to be a string.")?;
+
+        Ok(Submission {
+            day,
+            part: part as u8,
+            answer: answer.clone(),
+            outcome: Outcome::from_str(outcome)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, Submission, Submissions};
+    use crate::day;
+
+    fn submission(answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            day: day!(7),
+            part: 1,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", Some(Outcome::Correct)),
+            ("That's not the right answer; your answer is too high.", Some(Outcome::TooHigh)),
+            ("That's not the right answer; your answer is too low.", Some(Outcome::TooLow)),
+            ("That's not the right answer. If you're stuck...", Some(Outcome::Incorrect)),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.", Some(Outcome::RateLimited)),
+            ("You don't seem to be solving the right level.", None),
+        ];
+
+        for (response, expected) in cases {
+            assert_eq!(Outcome::parse(response), expected, "{response}");
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut submissions = Submissions::default();
+        submissions.push(submission("abc", Outcome::Incorrect));
+        submissions.push(submission("100", Outcome::TooHigh));
+        submissions.push(submission("10", Outcome::TooLow));
+        submissions.push(submission("50", Outcome::RateLimited));
+
+        assert!(submissions.refusal(day!(7), 1, "abc").is_some());
+        assert!(submissions.refusal(day!(7), 1, "150").is_some());
+        assert!(submissions.refusal(day!(7), 1, "100").is_some());
+        assert!(submissions.refusal(day!(7), 1, "5").is_some());
+        assert_eq!(submissions.refusal(day!(7), 1, "50"), None);
+        assert_eq!(submissions.refusal(day!(7), 1, "xyz"), None);
+        assert_eq!(submissions.refusal(day!(7), 2, "abc"), None);
+    }
+
+    #[test]
+    fn refuses_after_correct_answer() {
+        let mut submissions = Submissions::default();
+        submissions.push(submission("42", Outcome::Correct));
+        assert!(submissions.refusal(day!(7), 1, "43").is_some());
+        assert!(submissions.refusal(day!(7), 1, "42").is_some());
+        assert_eq!(submissions.refusal(day!(7), 2, "42"), None);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut submissions = Submissions::default();
+        submissions.push(submission("42", Outcome::TooLow));
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
@@ -1,17 +1,60 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::record::Failure;
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single day of a year.
+/// `part_1` and `part_2` hold the formatted median and spread, `total_nanos` the sum of medians.
+/// `failure` is set when the solution panicked or timed out, the parts that finished before are kept.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
     pub total_nanos: f64,
+    pub failure: Option<Failure>,
+}
+
+/// Direction of a change in run time, relative to the threshold passed to [`Timings::compare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Regression,
+    Improvement,
+    Unchanged,
+}
+
+/// Change of the median run time of a single part between a stored and a fresh benchmark.
+#[derive(Clone, Debug)]
+pub struct TimingComparison {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub baseline: BenchStats,
+    pub current: BenchStats,
+    pub change_percent: f64,
+    pub change: Change,
+}
+
+impl Timing {
+    /// Timings are identified by year and day.
+    pub fn key(&self) -> (Year, Day) {
+        (self.year, self.day)
+    }
+
+    pub fn part_stats(&self, part: u8) -> Option<&BenchStats> {
+        match part {
+            1 => self.part_1_stats.as_ref(),
+            2 => self.part_2_stats.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -46,12 +89,12 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.key() == timing.key()) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by(|a, b| a.key().cmp(&b.key()));
         Timings { data }
     }
 
@@ -60,10 +103,56 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Compare medians of `current` against `self` for every part that has statistics in both.
+    /// Changes larger than `threshold_percent` count as regression or improvement.
+    pub fn compare(&self, current: &Self, threshold_percent: f64) -> Vec<TimingComparison> {
+        let mut comparisons = vec![];
+
+        for timing in &current.data {
+            let Some(stored) = self.data.iter().find(|t| t.key() == timing.key()) else {
+                continue;
+            };
+
+            for part in [1, 2] {
+                let (Some(baseline), Some(current)) =
+                    (stored.part_stats(part), timing.part_stats(part))
+                else {
+                    continue;
+                };
+
+                // a change relative to a zero median is undefined.
+                if baseline.median == 0.0 {
+                    continue;
+                }
+
+                let change_percent = (current.median - baseline.median) / baseline.median * 100.0;
+                let change = if change_percent > threshold_percent {
+                    Change::Regression
+                } else if change_percent < -threshold_percent {
+                    Change::Improvement
+                } else {
+                    Change::Unchanged
+                };
+
+                comparisons.push(TimingComparison {
+                    year: timing.year,
+                    day: timing.day,
+                    part,
+                    baseline: *baseline,
+                    current: *current,
+                    change_percent,
+                    change,
+                });
+            }
+        }
+
+        comparisons
+    }
+
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.key() == (year, day) && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -111,6 +200,7 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
@@ -133,6 +223,27 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        map.insert(
+            "failure".into(),
+            match &value.failure {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -145,6 +256,15 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        // timings stored before multi-year support belong to the configured year.
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+            None => Year::from_env().ok_or("Expected timing.year to be set, or AOC_YEAR.")?,
+        };
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
@@ -161,25 +281,46 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // stats are optional, timings stored before they were introduced do not have them.
+        let part_1_stats = parse_optional_stats(json.get("part_1_stats"))?;
+        let part_2_stats = parse_optional_stats(json.get("part_2_stats"))?;
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // failures are optional, timings stored before they were recorded do not have them.
+        let failure = match json.get("failure") {
+            Some(v) if !v.is_null() => Some(Failure::try_from(v)?),
+            _ => None,
+        };
+
         Ok(Timing {
+            year,
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
             total_nanos,
+            failure,
         })
     }
 }
 
+fn parse_optional_stats(value: Option<&JsonValue>) -> Result<Option<BenchStats>, String> {
+    match value {
+        Some(v) if !v.is_null() => BenchStats::try_from(v).map(Some),
+        _ => Ok(None),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, year};
 
     use super::{Timing, Timings};
 
@@ -187,42 +328,82 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 3e+10,
+                    failure: None,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 7e+10,
+                    failure: None,
                 },
                 Timing {
+                    year: year!(2024),
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 4e+10,
+                    failure: None,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{record::Failure, timings::Timings},
+            year,
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
+            assert_eq!(timing.year, year!(2023));
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "2ns ± 1ns", "part_2": null, "part_1_stats": { "min": 1, "median": 2, "mean": 2, "p95": 3, "stddev": 1, "samples": 3 }, "part_2_stats": null, "total_nanos": 2 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.unwrap();
+            assert_eq!((stats.min, stats.median, stats.p95), (1.0, 2.0, 3.0));
+            assert_eq!(stats.samples, 3);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_failures() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "05", "part_1": null, "part_2": null, "total_nanos": 0, "failure": { "kind": "timed_out" } }, { "year": "2024", "day": "16", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "failure": { "kind": "panicked", "message": "oh no" } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].failure, Some(Failure::TimedOut));
+            assert_eq!(
+                timings.data[1].failure,
+                Some(Failure::Panicked("oh no".into()))
+            );
+            assert_eq!(timings.data[1].part_1, Some("1ms".to_string()));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -272,48 +453,126 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 3_000_000_000_f64,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 1_000_000_000_f64,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 0.0,
+                    failure: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
+        }
+    }
+
+    mod compare {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::stats::BenchStats,
+            template::timings::{Change, Timing, Timings},
+            year,
+        };
+
+        fn timings(part_1_nanos: Option<u64>, part_2_nanos: Option<u64>) -> Timings {
+            let stats = |nanos: Option<u64>| {
+                nanos.map(|n| BenchStats::from_samples(&[Duration::from_nanos(n)]))
+            };
+
+            Timings {
+                data: vec![Timing {
+                    year: year!(2024),
+                    day: day!(1),
+                    part_1: None,
+                    part_2: None,
+                    part_1_stats: stats(part_1_nanos),
+                    part_2_stats: stats(part_2_nanos),
+                    total_nanos: 0_f64,
+                    failure: None,
+                }],
+            }
+        }
+
+        #[test]
+        fn flags_regressions_and_improvements() {
+            let stored = timings(Some(100), Some(100));
+            let comparisons = stored.compare(&timings(Some(120), Some(80)), 10.0);
+            assert_eq!(comparisons.len(), 2);
+            assert_eq!(comparisons[0].change, Change::Regression);
+            assert_eq!(comparisons[0].change_percent, 20.0);
+            assert_eq!(comparisons[1].change, Change::Improvement);
+        }
+
+        #[test]
+        fn ignores_changes_within_threshold() {
+            let stored = timings(Some(100), Some(100));
+            let comparisons = stored.compare(&timings(Some(105), Some(95)), 10.0);
+            assert!(comparisons.iter().all(|c| c.change == Change::Unchanged));
+        }
+
+        #[test]
+        fn skips_parts_without_baseline() {
+            let stored = timings(Some(100), None);
+            let comparisons = stored.compare(&timings(Some(100), Some(100)), 10.0);
+            assert_eq!(comparisons.len(), 1);
+            assert_eq!(comparisons[0].part, 1);
+            assert!(Timings::default().compare(&stored, 10.0).is_empty());
+        }
+
+        #[test]
+        fn skips_parts_with_zero_baseline() {
+            let stored = timings(Some(0), Some(100));
+            let comparisons = stored.compare(&timings(Some(50), Some(100)), 10.0);
+            assert_eq!(comparisons.len(), 1);
+            assert_eq!(comparisons[0].part, 2);
+            assert!(comparisons[0].change_percent.is_finite());
         }
     }
 
@@ -321,6 +580,7 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         use super::get_mock_timings;
@@ -330,10 +590,14 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 0_f64,
+                    failure: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -350,10 +614,14 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2024),
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
                     total_nanos: 0_f64,
+                    failure: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -365,6 +633,29 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_same_day_of_other_years() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    year: year!(2023),
+                    day: day!(2),
+                    part_1: None,
+                    part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    total_nanos: 0_f64,
+                    failure: None,
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].key(), (year!(2023), day!(2)));
+            assert_eq!(merged.data[2].key(), (year!(2024), day!(2)));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -0,0 +1,284 @@
+/// Append-only log of benchmark results, one JSON object per line and part.
+/// Unlike `timings.json`, older results are kept, so the trend of a solution can be inspected.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+use crate::template::timings::Timings;
+use crate::template::{Day, Year};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.jsonl";
+
+static SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub stats: BenchStats,
+    /// Short hash of the checked out commit, `None` outside of a git repository.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Creates one entry per benched part of `timings`, stamped with the current commit and time.
+pub fn entries_from_timings(timings: &Timings) -> Vec<HistoryEntry> {
+    let commit = current_commit();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    timings
+        .data
+        .iter()
+        .flat_map(|timing| {
+            [1, 2].into_iter().filter_map(|part| {
+                timing.part_stats(part).map(|stats| HistoryEntry {
+                    year: timing.year,
+                    day: timing.day,
+                    part,
+                    stats: *stats,
+                    commit: commit.clone(),
+                    timestamp,
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn append(entries: &[HistoryEntry]) -> Result<(), Error> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+
+    for entry in entries {
+        writeln!(file, "{}", JsonValue::from(entry).stringify().unwrap())?;
+    }
+
+    Ok(())
+}
+
+/// Reads all entries for a day of `year` in the order they were recorded. If not present, returns no entries.
+pub fn read_day(year: Year, day: Day) -> Result<Vec<HistoryEntry>, String> {
+    let Ok(content) = fs::read_to_string(HISTORY_FILE_PATH) else {
+        return Ok(vec![]);
+    };
+
+    let entries = parse(&content)?;
+    Ok(entries
+        .into_iter()
+        .filter(|e| e.year == year && e.day == day)
+        .collect())
+}
+
+fn parse(content: &str) -> Result<Vec<HistoryEntry>, String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("history line is not valid JSON."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?;
+    Some(hash.trim().to_string())
+}
+
+/// Renders values as a line of block characters scaled between their minimum and maximum.
+pub fn sparkline(values: &[f64]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            if range == 0.0 {
+                return SPARK_CHARS[0];
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = ((value - min) / range * (SPARK_CHARS.len() - 1) as f64).round() as usize;
+            SPARK_CHARS[index]
+        })
+        .collect()
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // civil-from-days, see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        // entries recorded before multi-year support belong to the configured year.
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected entry.year to be a Year struct.")?,
+            None => Year::from_env().ok_or("Expected entry.year to be set, or AOC_YEAR.")?,
+        };
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.part to be a number.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected entry to have key `stats`.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        Ok(HistoryEntry {
+            year,
+            day,
+            part: part as u8,
+            stats: BenchStats::try_from(stats)?,
+            commit: commit.cloned(),
+            timestamp: timestamp as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{format_timestamp, parse, sparkline, HistoryEntry};
+    use crate::{day, template::stats::BenchStats, year};
+
+    fn entry(part: u8, commit: Option<&str>) -> HistoryEntry {
+        HistoryEntry {
+            year: year!(2024),
+            day: day!(6),
+            part,
+            stats: BenchStats::from_samples(&[Duration::from_micros(12)]),
+            commit: commit.map(String::from),
+            timestamp: 1_733_443_200,
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let entries = [entry(1, Some("3a5e555")), entry(2, None)];
+        let content: String = entries
+            .iter()
+            .map(|e| JsonValue::from(e).stringify().unwrap() + "\n")
+            .collect();
+        assert_eq!(parse(&content), Ok(entries.to_vec()));
+    }
+
+    #[test]
+    fn fails_on_malformed_lines() {
+        assert!(parse("{ \"day\": \"06\" }\n").is_err());
+        assert_eq!(parse("\n\n"), Ok(vec![]));
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5]), "▁█▅");
+        assert_eq!(sparkline(&[3.0, 3.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_443_200), "2024-12-06 00:00");
+        assert_eq!(format_timestamp(951_827_696), "2000-02-29 12:34");
+    }
+}